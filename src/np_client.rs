@@ -2,14 +2,17 @@ use reqwest::Client;
 use serde::Serialize;
 use url::Url;
 
+pub mod common;
 pub mod counterparty;
-mod date_format;
+pub(crate) mod date_format;
 mod deserializer;
-mod address;
+pub mod edrpou;
+pub mod address;
 pub mod en;
 pub mod helper_structs;
 pub mod res_template;
 pub mod internet_document;
+pub mod phone;
 mod tracking;
 
 const URL: &str = "https://api.novaposhta.ua/v2.0/json/";
@@ -31,6 +34,7 @@ pub struct NPRequest<'a, T: Serialize> {
 }
 
 impl NPClient {
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Result<Self, reqwest::Error> {
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(5))
@@ -63,19 +67,23 @@ impl NPClient {
         self.base_url = Url::parse(url).unwrap();
     }
 
-    pub fn address(&self) -> address::AddressHandler {
+    pub fn address(&self) -> address::AddressHandler<'_> {
         address::AddressHandler::new(self)
     }
 
-    pub fn tracking(&self) -> tracking::TrackingHandler {
+    pub fn tracking(&self) -> tracking::TrackingHandler<'_> {
         tracking::TrackingHandler::new(self)
     }
 
-    pub fn counterparty(&self) -> counterparty::CounterpartyHandler {
+    pub fn common(&self) -> common::CommonHandler<'_> {
+        common::CommonHandler::new(self)
+    }
+
+    pub fn counterparty(&self) -> counterparty::CounterpartyHandler<'_> {
         counterparty::CounterpartyHandler::new(self)
     }
 
-    pub fn i_document(&self) -> internet_document::IDocumentHandler {
+    pub fn i_document(&self) -> internet_document::IDocumentHandler<'_> {
         internet_document::IDocumentHandler::new(self)
     }
 
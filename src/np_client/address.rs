@@ -1,12 +1,23 @@
-mod settlements;
-mod cities;
-mod warehouses;
-use settlements::SearchSettlementsBuilder;
+pub mod areas;
+pub mod autocomplete;
+pub mod settlements;
+pub mod cities;
+pub mod place_search;
+pub mod schedule;
+pub mod streets;
+pub mod warehouses;
+pub mod warehouse_directory;
+pub mod warehouse_index;
+use autocomplete::AddressAutocomplete;
+use areas::{GetAreasBuilder, GetSettlementAreasBuilder, GetSettlementRegionsBuilder};
+use settlements::{GetSettlementsBuilder, SearchSettlementsBuilder};
 use cities::GetCitiesBuilder;
+use streets::{GetStreetsBuilder, SearchSettlementStreetsBuilder};
 
-use self::warehouses::GetWarehousesBuilder;
+use self::warehouses::{GetWarehouseTypesBuilder, GetWarehousesBuilder};
 
 use super::NPClient;
+use super::helper_structs::{AreaID, CityID, SettlementID};
 
 pub struct AddressHandler<'c> {
     client: &'c NPClient,
@@ -21,6 +32,16 @@ impl<'cli> AddressHandler<'cli> {
         SearchSettlementsBuilder::new(self.client, val)
     }
 
+    /// Full settlement directory, paged.
+    pub fn get_settlements(&self) -> GetSettlementsBuilder<'cli> {
+        GetSettlementsBuilder::new(self.client)
+    }
+
+    /// Stateful search for checkout address fields, see [`AddressAutocomplete`].
+    pub fn autocomplete(&self) -> AddressAutocomplete<'cli> {
+        AddressAutocomplete::new(self.client)
+    }
+
     pub fn get_cities(&self) -> GetCitiesBuilder<'cli> {
         GetCitiesBuilder::new(self.client)
     }
@@ -28,4 +49,28 @@ impl<'cli> AddressHandler<'cli> {
     pub fn get_warehouses(&self) -> GetWarehousesBuilder<'cli> {
         GetWarehousesBuilder::new(self.client)
     }
+
+    pub fn get_warehouse_types(&self) -> GetWarehouseTypesBuilder<'cli> {
+        GetWarehouseTypesBuilder::new(self.client)
+    }
+
+    pub fn get_areas(&self) -> GetAreasBuilder<'cli> {
+        GetAreasBuilder::new(self.client)
+    }
+
+    pub fn get_settlement_areas(&self) -> GetSettlementAreasBuilder<'cli> {
+        GetSettlementAreasBuilder::new(self.client)
+    }
+
+    pub fn get_settlement_regions(&self, area_ref: AreaID) -> GetSettlementRegionsBuilder<'cli> {
+        GetSettlementRegionsBuilder::new(self.client, area_ref)
+    }
+
+    pub fn search_settlement_streets(&self, settlement_ref: SettlementID, query: String) -> SearchSettlementStreetsBuilder<'cli> {
+        SearchSettlementStreetsBuilder::new(self.client, settlement_ref, query)
+    }
+
+    pub fn get_streets(&self, city_ref: CityID, find_by_string: String) -> GetStreetsBuilder<'cli> {
+        GetStreetsBuilder::new(self.client, city_ref).find_by_string(find_by_string)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::np_client::helper_structs::{AreaID, CityID, RegionID};
+use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
+
+/// Oblast or district, the responses differ only in the type of `Ref`.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AdminUnit<ID> {
+    r#ref: ID,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    areas_center: Option<CityID>,
+    description: String,
+    #[serde(default)]
+    description_ru: String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    region_type: Option<String>,
+}
+
+impl<ID: Copy> AdminUnit<ID> {
+    pub fn r#ref(&self) -> ID {
+        self.r#ref
+    }
+
+    pub fn areas_center(&self) -> Option<CityID> {
+        self.areas_center
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_ru(&self) -> &str {
+        &self.description_ru
+    }
+}
+
+/// Oblast as returned by `getAreas` and `getSettlementAreas`.
+pub type Area = AdminUnit<AreaID>;
+
+/// District (raion) of an oblast as returned by `getSettlementCountryRegion`.
+pub type Region = AdminUnit<RegionID>;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetAreasBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+}
+
+impl<'cli> GetAreasBuilder<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<Area>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "getAreas",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetSettlementAreasBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#ref: Option<AreaID>,
+}
+
+impl<'cli> GetSettlementAreasBuilder<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self {
+            client,
+            r#ref: None,
+        }
+    }
+
+    pub fn area_ref(mut self, area_ref: AreaID) -> Self {
+        self.r#ref = Some(area_ref);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<Area>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "getSettlementAreas",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetSettlementRegionsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    area_ref: AreaID,
+}
+
+impl<'cli> GetSettlementRegionsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, area_ref: AreaID) -> Self {
+        Self {
+            client,
+            area_ref,
+        }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<Region>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "getSettlementCountryRegion",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
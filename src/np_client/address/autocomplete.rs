@@ -0,0 +1,424 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::np_client::helper_structs::{AddressID, CityID, CounterpartyID, SettlementID, StreetRef, WarehouseID};
+use crate::np_client::NPClient;
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+const DEFAULT_LIMIT: u16 = 20;
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(600);
+const DEFAULT_CACHE_CAPACITY: usize = 1000;
+
+#[derive(thiserror::Error, Debug)]
+pub enum AutocompleteError {
+    /// A newer query for the same field came in during the debounce delay.
+    /// The result of that query is the one to show, so this one can be
+    /// ignored.
+    #[error("Query superseded by a newer one")]
+    Superseded,
+    #[error("Request rejected by the API: {0:?}")]
+    Rejected(Vec<String>),
+    #[error("Suggestion of a different kind expected")]
+    UnexpectedKind,
+    /// The settlement has no street list of its own; only warehouse
+    /// delivery can be offered there.
+    #[error("Streets of the settlement can't be searched")]
+    NoStreets,
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestionKind {
+    Settlement {
+        settlement_ref: SettlementID,
+        address_delivery_allowed: bool,
+        streets_availability: bool,
+    },
+    Warehouse(WarehouseID),
+    Street(StreetRef),
+}
+
+/// Entry of any autocomplete field, ready to be shown in a dropdown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    kind: SuggestionKind,
+    label: String,
+    city_ref: CityID,
+    /// Lowercased name used to narrow cached results down.
+    name: String,
+}
+
+impl Suggestion {
+    pub fn kind(&self) -> SuggestionKind {
+        self.kind
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// City the suggestion belongs to. For a settlement this is its delivery
+    /// city, which scopes the warehouse field.
+    pub fn city_ref(&self) -> CityID {
+        self.city_ref
+    }
+}
+
+/// Recipient city and address, as taken by
+/// `CreateIDocumentBuilder::recipient_info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedAddress {
+    city: CityID,
+    address: AddressID,
+}
+
+impl ResolvedAddress {
+    pub fn city(&self) -> CityID {
+        self.city
+    }
+
+    pub fn address(&self) -> AddressID {
+        self.address
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Field {
+    Settlement,
+    Warehouse,
+    Street,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    field: Field,
+    /// Ref the field is limited to: the city for warehouses, the settlement
+    /// for streets.
+    scope: Option<Uuid>,
+    query: String,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    suggestions: Vec<Suggestion>,
+    /// Fewer results than the limit came back, so the entry holds every
+    /// match of its query.
+    complete: bool,
+    stored_at: Instant,
+}
+
+/// Search behind the settlement → warehouse and settlement → street →
+/// building steps of a checkout form.
+///
+/// Queries go through an [`AutocompleteSession`], one per form, which
+/// debounces each of its fields. Results are shared by all sessions and
+/// cached per query for the cache TTL; a query extending a cached one that
+/// returned everything is answered from the cache without a request.
+#[derive(Debug)]
+pub struct AddressAutocomplete<'cli> {
+    client: &'cli NPClient,
+    debounce: Duration,
+    limit: u16,
+    cache_ttl: Duration,
+    cache_capacity: usize,
+    cache: Mutex<HashMap<CacheKey, CacheEntry>>,
+}
+
+impl<'cli> AddressAutocomplete<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self {
+            client,
+            debounce: DEFAULT_DEBOUNCE,
+            limit: DEFAULT_LIMIT,
+            cache_ttl: DEFAULT_CACHE_TTL,
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Defaults to 300 ms.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Suggestions per query, defaults to 20.
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// How long results are reused, defaults to 10 minutes.
+    pub fn cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Cached queries kept at most, defaults to 1000. The oldest one is
+    /// dropped to make room.
+    pub fn cache_capacity(mut self, cache_capacity: usize) -> Self {
+        self.cache_capacity = cache_capacity;
+        self
+    }
+
+    /// Debounce state for one form.
+    pub fn session(&self) -> AutocompleteSession<'_, 'cli> {
+        AutocompleteSession {
+            autocomplete: self,
+            generations: Default::default(),
+        }
+    }
+
+    /// Warehouse refs are accepted as the recipient address as is, so no
+    /// request is made.
+    pub fn resolve_warehouse(&self, choice: &Suggestion) -> Result<ResolvedAddress, AutocompleteError> {
+        match choice.kind {
+            SuggestionKind::Warehouse(warehouse_ref) => Ok(ResolvedAddress {
+                city: choice.city_ref,
+                address: warehouse_ref.into(),
+            }),
+            _ => Err(AutocompleteError::UnexpectedKind),
+        }
+    }
+
+    /// Saves the building on the chosen street as an address of `recipient`.
+    pub async fn resolve_street(
+        &self,
+        choice: &Suggestion,
+        recipient: CounterpartyID,
+        building_number: String,
+        flat: Option<String>,
+    ) -> Result<ResolvedAddress, AutocompleteError> {
+        let SuggestionKind::Street(street_ref) = choice.kind else {
+            return Err(AutocompleteError::UnexpectedKind);
+        };
+
+        let mut builder = self.client
+            .counterparty()
+            .create_address(recipient, street_ref, building_number);
+        if let Some(flat) = flat {
+            builder = builder.flat(flat);
+        }
+        let res = builder.send().await?;
+        match res.data.first() {
+            Some(address) if res.success => Ok(ResolvedAddress {
+                city: choice.city_ref,
+                address: address.address_id(),
+            }),
+            _ => Err(AutocompleteError::Rejected(res.errors)),
+        }
+    }
+
+    async fn search_settlements(&self, key: CacheKey) -> Result<Vec<Suggestion>, AutocompleteError> {
+        if let Some(cached) = self.cached(&key) {
+            return Ok(cached);
+        }
+
+        let res = self.client
+            .address()
+            .search_settlements(key.query.clone())
+            .limit(self.limit)
+            .send()
+            .await?;
+        if !res.success {
+            return Err(AutocompleteError::Rejected(res.errors));
+        }
+
+        let suggestions = res.data
+            .iter()
+            .flat_map(|settlements| settlements.settlements())
+            .map(|settlement| Suggestion {
+                kind: SuggestionKind::Settlement {
+                    settlement_ref: settlement.r#ref(),
+                    address_delivery_allowed: settlement.address_delivery_allowed(),
+                    streets_availability: settlement.streets_availability(),
+                },
+                label: settlement.full_name().to_owned(),
+                city_ref: settlement.delivery_city(),
+                name: settlement.main_description().to_lowercase(),
+            })
+            .collect();
+        Ok(self.store(key, suggestions))
+    }
+
+    async fn search_warehouses(&self, city_ref: CityID, key: CacheKey) -> Result<Vec<Suggestion>, AutocompleteError> {
+        if let Some(cached) = self.cached(&key) {
+            return Ok(cached);
+        }
+
+        let res = self.client
+            .address()
+            .get_warehouses()
+            .city_ref(city_ref)
+            .find_by_string(key.query.clone())
+            .limit(self.limit)
+            .send()
+            .await?;
+        if !res.success {
+            return Err(AutocompleteError::Rejected(res.errors));
+        }
+
+        let suggestions = res.data
+            .iter()
+            .map(|warehouse| Suggestion {
+                kind: SuggestionKind::Warehouse(warehouse.r#ref()),
+                label: warehouse.description().to_owned(),
+                city_ref: warehouse.city_ref(),
+                name: warehouse.description().to_lowercase(),
+            })
+            .collect();
+        Ok(self.store(key, suggestions))
+    }
+
+    async fn search_streets(&self, city_ref: CityID, key: CacheKey) -> Result<Vec<Suggestion>, AutocompleteError> {
+        if let Some(cached) = self.cached(&key) {
+            return Ok(cached);
+        }
+
+        let res = self.client
+            .address()
+            .get_streets(city_ref, key.query.clone())
+            .limit(self.limit)
+            .send()
+            .await?;
+        if !res.success {
+            return Err(AutocompleteError::Rejected(res.errors));
+        }
+
+        let suggestions = res.data
+            .iter()
+            .map(|street| Suggestion {
+                kind: SuggestionKind::Street(street.r#ref()),
+                label: format!("{} {}", street.streets_type(), street.description()),
+                city_ref,
+                name: street.description().to_lowercase(),
+            })
+            .collect();
+        Ok(self.store(key, suggestions))
+    }
+
+    fn cached(&self, key: &CacheKey) -> Option<Vec<Suggestion>> {
+        let cache = self.cache.lock().unwrap();
+        let fresh = |entry: &&CacheEntry| entry.stored_at.elapsed() < self.cache_ttl;
+        if let Some(entry) = cache.get(key).filter(fresh) {
+            return Some(entry.suggestions.clone());
+        }
+
+        key.query
+            .char_indices()
+            .rev()
+            .filter(|(end, _)| *end > 0)
+            .find_map(|(end, _)| {
+                let prefix = CacheKey { query: key.query[..end].to_owned(), ..key.clone() };
+                cache.get(&prefix).filter(fresh).filter(|entry| entry.complete)
+            })
+            .map(|entry| {
+                entry.suggestions
+                    .iter()
+                    .filter(|suggestion| suggestion.name.contains(&key.query))
+                    .cloned()
+                    .collect()
+            })
+    }
+
+    fn store(&self, key: CacheKey, suggestions: Vec<Suggestion>) -> Vec<Suggestion> {
+        let entry = CacheEntry {
+            complete: suggestions.len() < self.limit as usize,
+            suggestions: suggestions.clone(),
+            stored_at: Instant::now(),
+        };
+
+        let mut cache = self.cache.lock().unwrap();
+        if !cache.contains_key(&key) && cache.len() >= self.cache_capacity {
+            cache.retain(|_, entry| entry.stored_at.elapsed() < self.cache_ttl);
+            if cache.len() >= self.cache_capacity {
+                let oldest = cache
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.stored_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    cache.remove(&oldest);
+                }
+            }
+        }
+        if self.cache_capacity > 0 {
+            cache.insert(key, entry);
+        }
+        suggestions
+    }
+}
+
+/// Fields of one checkout form. A query waits for the debounce delay and
+/// gives up with [`AutocompleteError::Superseded`] if a newer query for the
+/// same field of this session came in meanwhile; other sessions don't
+/// interfere.
+#[derive(Debug)]
+pub struct AutocompleteSession<'a, 'cli> {
+    autocomplete: &'a AddressAutocomplete<'cli>,
+    generations: [AtomicU64; 3],
+}
+
+impl AutocompleteSession<'_, '_> {
+    pub async fn settlements(&self, query: &str) -> Result<Vec<Suggestion>, AutocompleteError> {
+        let Some(key) = self.prepare(Field::Settlement, None, query).await? else {
+            return Ok(Vec::new());
+        };
+        self.autocomplete.search_settlements(key).await
+    }
+
+    /// Warehouses and postomats of `city_ref` matching their description or
+    /// number.
+    pub async fn warehouses(&self, city_ref: CityID, query: &str) -> Result<Vec<Suggestion>, AutocompleteError> {
+        let Some(key) = self.prepare(Field::Warehouse, Some(city_ref.as_uuid()), query).await? else {
+            return Ok(Vec::new());
+        };
+        self.autocomplete.search_warehouses(city_ref, key).await
+    }
+
+    /// Streets of the chosen settlement. Uses `getStreet` rather than
+    /// `searchSettlementStreets`, as only its refs are accepted when saving
+    /// the recipient address. `getStreet` lists the streets of the delivery
+    /// city, which is the settlement itself only when it has its own streets,
+    /// so settlements without `streets_availability` are refused.
+    pub async fn streets(&self, settlement: &Suggestion, query: &str) -> Result<Vec<Suggestion>, AutocompleteError> {
+        let SuggestionKind::Settlement { settlement_ref, streets_availability, .. } = settlement.kind else {
+            return Err(AutocompleteError::UnexpectedKind);
+        };
+        if !streets_availability {
+            return Err(AutocompleteError::NoStreets);
+        }
+
+        let Some(key) = self.prepare(Field::Street, Some(settlement_ref.as_uuid()), query).await? else {
+            return Ok(Vec::new());
+        };
+        self.autocomplete.search_streets(settlement.city_ref, key).await
+    }
+
+    /// Registers the query as the latest one of its field and waits out the
+    /// debounce delay. `None` for a blank query.
+    async fn prepare(&self, field: Field, scope: Option<Uuid>, query: &str) -> Result<Option<CacheKey>, AutocompleteError> {
+        let generation = &self.generations[field as usize];
+        let current = generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Ok(None);
+        }
+        let key = CacheKey { field, scope, query };
+        let debounce = self.autocomplete.debounce;
+        if self.autocomplete.cached(&key).is_some() || debounce.is_zero() {
+            return Ok(Some(key));
+        }
+
+        tokio::time::sleep(debounce).await;
+        if generation.load(Ordering::SeqCst) != current {
+            return Err(AutocompleteError::Superseded);
+        }
+        Ok(Some(key))
+    }
+}
@@ -3,6 +3,7 @@ use serde_with::{serde_as, BoolFromInt, DisplayFromStr, NoneAsEmptyString};
 use uuid::Uuid;
 
 use crate::np_client::{deserializer::deserialize_bool_from_str_num, NPClient, NPRequest, res_template::ResponseTemplate};
+use crate::np_client::helper_structs::{AreaID, CityID};
 
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
@@ -10,7 +11,7 @@ use crate::np_client::{deserializer::deserialize_bool_from_str_num, NPClient, NP
 pub struct City {
     description: String,
     description_ru: String,
-    r#ref: Uuid,
+    r#ref: CityID,
     #[serde_as(as = "NoneAsEmptyString")]
     delivery1: Option<String>,
     #[serde_as(as = "NoneAsEmptyString")]
@@ -25,7 +26,7 @@ pub struct City {
     delivery6: Option<String>,
     #[serde_as(as = "NoneAsEmptyString")]
     delivery7: Option<String>,
-    area: Uuid,
+    area: AreaID,
     settlement_type: Uuid,
     #[serde(deserialize_with = "deserialize_bool_from_str_num")]
     is_branch: bool,
@@ -42,6 +43,31 @@ pub struct City {
     area_description_ru: String,
 }
 
+impl City {
+    pub fn r#ref(&self) -> CityID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_ru(&self) -> &str {
+        &self.description_ru
+    }
+
+    /// Ref of the oblast, resolvable through `AddressHandler::get_areas`.
+    pub fn area(&self) -> AreaID {
+        self.area
+    }
+}
+
+impl From<&City> for CityID {
+    fn from(city: &City) -> Self {
+        city.r#ref
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GetCitiesBuilder<'cli> {
@@ -49,7 +75,7 @@ pub struct GetCitiesBuilder<'cli> {
     client: &'cli NPClient,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    city_ref: Option<Uuid>,
+    city_ref: Option<CityID>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     find_by_string: Option<String>,
@@ -88,7 +114,7 @@ impl<'cli> GetCitiesBuilder<'cli> {
         self
     }
 
-    pub fn city_ref(mut self, c_ref: Uuid) -> Self {
+    pub fn city_ref(mut self, c_ref: CityID) -> Self {
         self.city_ref = Some(c_ref);
         self
     }
@@ -0,0 +1,290 @@
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::np_client::NPClient;
+
+use super::cities::City;
+use super::settlements::{Settlement, SettlementEntry};
+use super::warehouse_directory::{is_last_page, DirectoryError};
+
+const PAGE_SIZE: u16 = 500;
+
+/// Names a place can be found by.
+pub trait PlaceNames {
+    fn names(&self) -> Vec<&str>;
+}
+
+impl PlaceNames for City {
+    fn names(&self) -> Vec<&str> {
+        vec![self.description(), self.description_ru()]
+    }
+}
+
+/// `searchSettlements` results have no Russian name; an index synced with
+/// [`PlaceIndex::sync_settlements`] has one.
+impl PlaceNames for Settlement {
+    fn names(&self) -> Vec<&str> {
+        vec![self.main_description()]
+    }
+}
+
+impl PlaceNames for SettlementEntry {
+    fn names(&self) -> Vec<&str> {
+        vec![self.description(), self.description_ru()]
+    }
+}
+
+/// How well a place matched a query, better matches compare lower.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchKind {
+    Exact,
+    Prefix,
+    /// A later word of the name starts with the query, e.g. "церк" for
+    /// "Біла Церква".
+    WordPrefix,
+    /// Prefix match with the given number of typos.
+    Fuzzy(u8),
+}
+
+/// Offline fuzzy search over cities or settlements.
+///
+/// Names and queries are compared in a folded Latin form, so Ukrainian,
+/// Russian and transliterated spellings ("Київ", "Киев", "Kyiv", "Kiev")
+/// all find the same city.
+#[derive(Debug)]
+pub struct PlaceIndex<T> {
+    places: Vec<T>,
+    keys: Vec<Vec<String>>,
+}
+
+impl<T: PlaceNames> PlaceIndex<T> {
+    pub fn new(places: Vec<T>) -> Self {
+        let keys = places
+            .iter()
+            .map(|place| place.names().into_iter().map(fold).collect())
+            .collect();
+        Self { places, keys }
+    }
+
+    pub fn places(&self) -> &[T] {
+        &self.places
+    }
+
+    /// Up to `limit` places matching `query`, best first. Among equally good
+    /// matches shorter names go first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<(&T, MatchKind)> {
+        let query = fold(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut found = self.keys
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, keys)| {
+                keys.iter()
+                    .filter_map(|key| match_key(key, &query).map(|kind| (kind, key.len())))
+                    .min()
+                    .map(|rank| (rank, idx))
+            })
+            .collect::<Vec<_>>();
+        found.sort();
+
+        found
+            .into_iter()
+            .take(limit)
+            .map(|((kind, _), idx)| (&self.places[idx], kind))
+            .collect()
+    }
+}
+
+impl<T: PlaceNames + Serialize + DeserializeOwned> PlaceIndex<T> {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DirectoryError> {
+        Ok(Self::new(serde_json::from_slice(&fs::read(path)?)?))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), DirectoryError> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(&self.places)?)?;
+        fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+}
+
+impl PlaceIndex<City> {
+    /// Downloads every city page by page.
+    pub async fn sync_cities(client: &NPClient) -> Result<Self, DirectoryError> {
+        let mut cities = Vec::new();
+        let mut page = 1;
+        loop {
+            let res = client
+                .address()
+                .get_cities()
+                .page(page)
+                .limit(PAGE_SIZE)
+                .send()
+                .await?;
+            if !res.success {
+                return Err(DirectoryError::Rejected(res.errors));
+            }
+
+            let last = is_last_page(&res, cities.len());
+            cities.extend(res.data);
+            if last {
+                break;
+            }
+            page += 1;
+        }
+        Ok(Self::new(cities))
+    }
+}
+
+impl PlaceIndex<SettlementEntry> {
+    /// Downloads every settlement page by page. There are tens of thousands
+    /// of them, so this takes a while.
+    pub async fn sync_settlements(client: &NPClient) -> Result<Self, DirectoryError> {
+        let mut settlements = Vec::new();
+        let mut page = 1;
+        loop {
+            let res = client
+                .address()
+                .get_settlements()
+                .page(page)
+                .limit(PAGE_SIZE)
+                .send()
+                .await?;
+            if !res.success {
+                return Err(DirectoryError::Rejected(res.errors));
+            }
+
+            let last = is_last_page(&res, settlements.len());
+            settlements.extend(res.data);
+            if last {
+                break;
+            }
+            page += 1;
+        }
+        Ok(Self::new(settlements))
+    }
+}
+
+fn match_key(key: &str, query: &str) -> Option<MatchKind> {
+    if key == query {
+        return Some(MatchKind::Exact);
+    }
+    if key.starts_with(query) {
+        return Some(MatchKind::Prefix);
+    }
+    let words = key.split(' ').collect::<Vec<_>>();
+    if words[1..].iter().any(|word| word.starts_with(query)) {
+        return Some(MatchKind::WordPrefix);
+    }
+
+    let allowed = match query.chars().count() {
+        0..=3 => return None,
+        4..=6 => 1,
+        _ => 2,
+    };
+    std::iter::once(key)
+        .chain(words[1..].iter().copied())
+        .map(|word| prefix_distance(word, query))
+        .filter(|distance| *distance <= allowed)
+        .min()
+        .map(|distance| MatchKind::Fuzzy(distance as u8))
+}
+
+/// Edit distance between `query` and the start of `word`, letting the
+/// query end anywhere within a character of its own length.
+fn prefix_distance(word: &str, query: &str) -> usize {
+    let word = word.chars().collect::<Vec<_>>();
+    let query = query.chars().collect::<Vec<_>>();
+    let len = query.len();
+
+    (len.saturating_sub(1)..=len + 1)
+        .map(|end| osa_distance(&word[..end.min(word.len())], &query))
+        .min()
+        .unwrap_or(usize::MAX)
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions
+/// and transpositions of adjacent characters.
+fn osa_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Lowercases, transliterates Cyrillic and folds spelling variants
+/// (y/i/j, g/h, doubled letters), keeping single spaces between words.
+fn fold(value: &str) -> String {
+    let mut latin = String::with_capacity(value.len());
+    for ch in value.to_lowercase().chars() {
+        match ch {
+            'а' => latin.push('a'),
+            'б' => latin.push('b'),
+            'в' => latin.push('v'),
+            'г' | 'ґ' => latin.push('h'),
+            'д' => latin.push('d'),
+            'е' | 'э' | 'ё' => latin.push('e'),
+            'є' => latin.push_str("ie"),
+            'ж' => latin.push_str("zh"),
+            'з' => latin.push('z'),
+            'и' | 'ы' | 'і' | 'ї' | 'й' => latin.push('i'),
+            'к' => latin.push('k'),
+            'л' => latin.push('l'),
+            'м' => latin.push('m'),
+            'н' => latin.push('n'),
+            'о' => latin.push('o'),
+            'п' => latin.push('p'),
+            'р' => latin.push('r'),
+            'с' => latin.push('s'),
+            'т' => latin.push('t'),
+            'у' => latin.push('u'),
+            'ф' => latin.push('f'),
+            'х' => latin.push_str("kh"),
+            'ц' => latin.push_str("ts"),
+            'ч' => latin.push_str("ch"),
+            'ш' => latin.push_str("sh"),
+            'щ' => latin.push_str("shch"),
+            'ю' => latin.push_str("iu"),
+            'я' => latin.push_str("ia"),
+            'y' | 'j' => latin.push('i'),
+            'g' => latin.push('h'),
+            'w' => latin.push('v'),
+            'x' => latin.push_str("ks"),
+            'a'..='z' | '0'..='9' => latin.push(ch),
+            '-' | ' ' | '.' | ',' => latin.push(' '),
+            // Soft and hard signs, apostrophes and the rest are dropped.
+            _ => {},
+        }
+    }
+
+    let mut folded = String::with_capacity(latin.len());
+    for ch in latin.trim().chars() {
+        if !folded.ends_with(ch) {
+            folded.push(ch);
+        }
+    }
+    folded
+}
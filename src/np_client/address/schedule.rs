@@ -0,0 +1,214 @@
+use chrono::{Datelike, Days, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+const TIME_FORMAT: &str = "%H:%M";
+
+/// Single opening range of a day. `end` of `23:59` or `24:00` means the
+/// range lasts until midnight; `end` before `start`, as in `22:00-02:00`,
+/// means it runs past midnight into the next day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpeningInterval {
+    start: NaiveTime,
+    end: NaiveTime,
+    /// Sent as `24:00`, which `NaiveTime` can't hold; `end` is `00:00` then.
+    closes_at_midnight: bool,
+}
+
+impl OpeningInterval {
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    /// `00:00` for a range ending at `24:00`, see
+    /// [`Self::closes_at_midnight`].
+    pub fn end(&self) -> NaiveTime {
+        self.end
+    }
+
+    /// The range ends at `24:00`.
+    pub fn closes_at_midnight(&self) -> bool {
+        self.closes_at_midnight
+    }
+
+    pub fn is_overnight(&self) -> bool {
+        !self.closes_at_midnight && self.end < self.start
+    }
+
+    fn until_midnight(&self) -> bool {
+        self.closes_at_midnight || self.end >= NaiveTime::from_hms_opt(23, 59, 0).unwrap()
+    }
+
+    /// Whether the range covers `time` of the day it starts on or, for an
+    /// overnight range, of the next day.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        self.contains_same_day(time) || (self.is_overnight() && time < self.end)
+    }
+
+    fn contains_same_day(&self, time: NaiveTime) -> bool {
+        self.start <= time && (time < self.end || self.is_overnight() || self.until_midnight())
+    }
+
+    /// Parses `08:00-20:00`.
+    fn parse(value: &str) -> Option<Self> {
+        let (start, end) = value.trim().split_once('-')?;
+        let start = NaiveTime::parse_from_str(start.trim(), TIME_FORMAT).ok()?;
+        let (end, closes_at_midnight) = match end.trim() {
+            "24:00" => (NaiveTime::MIN, true),
+            end => (NaiveTime::parse_from_str(end, TIME_FORMAT).ok()?, false),
+        };
+        Some(Self { start, end, closes_at_midnight })
+    }
+
+    fn format(&self) -> String {
+        let end = if self.closes_at_midnight {
+            "24:00".to_owned()
+        } else {
+            self.end.format(TIME_FORMAT).to_string()
+        };
+        format!("{}-{}", self.start.format(TIME_FORMAT), end)
+    }
+}
+
+/// Weekly opening hours, as in the `Schedule`, `Reception` and `Delivery`
+/// fields of a warehouse.
+///
+/// Days come as `08:00-20:00`, several ranges separated by commas, or `-`
+/// when closed. A day that can't be parsed doesn't fail the whole warehouse
+/// list: it is kept as sent, see [`Self::unparsed`], and has no intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawSchedule", into = "RawSchedule")]
+pub struct Schedule {
+    days: [Vec<OpeningInterval>; 7],
+    unparsed: [Option<String>; 7],
+}
+
+impl Schedule {
+    /// Ranges starting on `weekday`, an overnight one included.
+    pub fn intervals(&self, weekday: Weekday) -> &[OpeningInterval] {
+        &self.days[weekday.num_days_from_monday() as usize]
+    }
+
+    /// The day as sent by the API when it couldn't be parsed. Queries see
+    /// such a day as closed, this tells it apart from a day off.
+    pub fn unparsed(&self, weekday: Weekday) -> Option<&str> {
+        self.unparsed[weekday.num_days_from_monday() as usize].as_deref()
+    }
+
+    pub fn works_on(&self, weekday: Weekday) -> bool {
+        !self.intervals(weekday).is_empty()
+    }
+
+    pub fn works_on_sundays(&self) -> bool {
+        self.works_on(Weekday::Sun)
+    }
+
+    pub fn is_open_at(&self, at: NaiveDateTime) -> bool {
+        self.interval_at(at).is_some()
+    }
+
+    /// Closing time of the range `at` falls in, e.g. to show "accepts
+    /// parcels until 19:00"; on the next day for an overnight range. `None`
+    /// when closed at that moment.
+    pub fn open_until(&self, at: NaiveDateTime) -> Option<NaiveTime> {
+        self.interval_at(at).map(|interval| interval.end)
+    }
+
+    /// Earliest moment from `after` on when the schedule is open; `after`
+    /// itself when already open. `None` for a schedule closed all week.
+    pub fn next_opening(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        if self.is_open_at(after) {
+            return Some(after);
+        }
+        (0..=7).find_map(|offset| {
+            let date = after.date().checked_add_days(Days::new(offset))?;
+            self.intervals(date.weekday())
+                .iter()
+                .map(|interval| date.and_time(interval.start))
+                .filter(|start| *start > after)
+                .min()
+        })
+    }
+
+    fn interval_at(&self, at: NaiveDateTime) -> Option<&OpeningInterval> {
+        let time = at.time();
+        self.intervals(at.weekday())
+            .iter()
+            .find(|interval| interval.contains_same_day(time))
+            .or_else(|| {
+                self.intervals(at.weekday().pred())
+                    .iter()
+                    .find(|interval| interval.is_overnight() && time < interval.end)
+            })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawSchedule {
+    monday: String,
+    tuesday: String,
+    wednesday: String,
+    thursday: String,
+    friday: String,
+    saturday: String,
+    sunday: String,
+}
+
+/// `None` when any of the ranges can't be parsed.
+fn parse_day(value: &str) -> Option<Vec<OpeningInterval>> {
+    if value.trim() == "-" {
+        return Some(Vec::new());
+    }
+    let mut intervals = value
+        .split(',')
+        .map(OpeningInterval::parse)
+        .collect::<Option<Vec<_>>>()?;
+    intervals.sort_by_key(|interval| interval.start);
+    Some(intervals)
+}
+
+fn format_day(intervals: &[OpeningInterval], unparsed: Option<String>) -> String {
+    if let Some(raw) = unparsed {
+        return raw;
+    }
+    if intervals.is_empty() {
+        return "-".to_owned();
+    }
+    intervals
+        .iter()
+        .map(OpeningInterval::format)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl From<RawSchedule> for Schedule {
+    fn from(raw: RawSchedule) -> Self {
+        let raw_days = [raw.monday, raw.tuesday, raw.wednesday, raw.thursday, raw.friday, raw.saturday, raw.sunday];
+        let mut days: [Vec<OpeningInterval>; 7] = Default::default();
+        let mut unparsed: [Option<String>; 7] = Default::default();
+        for (idx, raw_day) in raw_days.into_iter().enumerate() {
+            match parse_day(&raw_day) {
+                Some(intervals) => days[idx] = intervals,
+                None => unparsed[idx] = Some(raw_day),
+            }
+        }
+        Self { days, unparsed }
+    }
+}
+
+impl From<Schedule> for RawSchedule {
+    fn from(schedule: Schedule) -> Self {
+        let mut unparsed = schedule.unparsed.into_iter();
+        let [monday, tuesday, wednesday, thursday, friday, saturday, sunday] =
+            schedule.days.map(|day| format_day(&day, unparsed.next().flatten()));
+        Self {
+            monday,
+            tuesday,
+            wednesday,
+            thursday,
+            friday,
+            saturday,
+            sunday,
+        }
+    }
+}
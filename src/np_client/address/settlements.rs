@@ -1,9 +1,11 @@
 use serde::{Serialize, Deserialize};
-use uuid::Uuid;
+use serde_with::{serde_as, NoneAsEmptyString};
 
+use crate::np_client::deserializer::deserialize_bool_from_str_num;
+use crate::np_client::helper_structs::{AreaID, CityID, RegionID, SettlementID};
 use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Settlement {
     #[serde(rename = "Present")]
@@ -14,8 +16,8 @@ pub struct Settlement {
     area: String,
     region: String,
     settlement_type_code: String,
-    r#ref: Uuid,
-    delivery_city: Uuid,
+    r#ref: SettlementID,
+    delivery_city: CityID,
     address_delivery_allowed: bool,
     streets_availability: bool,
     parent_region_types: String,
@@ -24,6 +26,35 @@ pub struct Settlement {
     region_types_code: String,
 }
 
+impl Settlement {
+    pub fn r#ref(&self) -> SettlementID {
+        self.r#ref
+    }
+
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    /// Name alone, without the type, district and oblast of `full_name`.
+    pub fn main_description(&self) -> &str {
+        &self.main_description
+    }
+
+    pub fn delivery_city(&self) -> CityID {
+        self.delivery_city
+    }
+
+    pub fn address_delivery_allowed(&self) -> bool {
+        self.address_delivery_allowed
+    }
+
+    /// Whether streets of the settlement can be looked up with
+    /// `AddressHandler::search_settlement_streets`.
+    pub fn streets_availability(&self) -> bool {
+        self.streets_availability
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Settlements {
@@ -31,6 +62,16 @@ pub struct Settlements {
     addresses: Vec<Settlement>,
 }
 
+impl Settlements {
+    pub fn total_count(&self) -> u16 {
+        self.total_count
+    }
+
+    pub fn settlements(&self) -> &[Settlement] {
+        &self.addresses
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SearchSettlementsBuilder<'cli> {
@@ -74,7 +115,7 @@ impl<'cli> SearchSettlementsBuilder<'cli> {
             called_method: "searchSettlements",
             method_properties: &self
         };
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
         .post(url)
         .json(&request)
@@ -83,4 +124,137 @@ impl<'cli> SearchSettlementsBuilder<'cli> {
         .json()
         .await
     }
-}
\ No newline at end of file
+}
+
+/// Entry of `Address.getSettlements`, the full settlement directory. Unlike
+/// [`Settlement`] it carries the Russian name but no delivery city.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SettlementEntry {
+    r#ref: SettlementID,
+    description: String,
+    description_ru: String,
+    description_translit: String,
+    settlement_type_description: String,
+    area: AreaID,
+    area_description: String,
+    /// Empty for settlements not inside a district, e.g. oblast centres.
+    #[serde_as(as = "NoneAsEmptyString")]
+    region: Option<RegionID>,
+    regions_description: String,
+    #[serde(deserialize_with = "deserialize_bool_from_str_num")]
+    warehouse: bool,
+}
+
+impl SettlementEntry {
+    pub fn r#ref(&self) -> SettlementID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_ru(&self) -> &str {
+        &self.description_ru
+    }
+
+    pub fn description_translit(&self) -> &str {
+        &self.description_translit
+    }
+
+    /// `місто`, `село`, ...
+    pub fn settlement_type_description(&self) -> &str {
+        &self.settlement_type_description
+    }
+
+    pub fn area(&self) -> AreaID {
+        self.area
+    }
+
+    pub fn area_description(&self) -> &str {
+        &self.area_description
+    }
+
+    pub fn region(&self) -> Option<RegionID> {
+        self.region
+    }
+
+    pub fn regions_description(&self) -> &str {
+        &self.regions_description
+    }
+
+    /// Whether the settlement has at least one warehouse.
+    pub fn has_warehouses(&self) -> bool {
+        self.warehouse
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetSettlementsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    area_ref: Option<AreaID>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    find_by_string: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u16>,
+}
+
+impl<'cli> GetSettlementsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self {
+            client,
+            area_ref: None,
+            find_by_string: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    pub fn area_ref(mut self, area_ref: AreaID) -> Self {
+        self.area_ref = Some(area_ref);
+        self
+    }
+
+    pub fn find_by_string(mut self, search_val: String) -> Self {
+        self.find_by_string = Some(search_val);
+        self
+    }
+
+    pub fn page(mut self, page: u16) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<SettlementEntry>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "AddressGeneral",
+            called_method: "getSettlements",
+            method_properties: &self
+        };
+
+        self.client.http_client
+            .post(self.client.base_url.clone())
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::np_client::helper_structs::{CityID, SettlementID, SettlementStreetID, StreetRef};
+use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
+
+/// Street of a settlement as returned by `searchSettlementStreets`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SettlementStreet {
+    #[serde(rename = "Present")]
+    full_name: String,
+    settlement_ref: SettlementID,
+    settlement_street_ref: SettlementStreetID,
+    settlement_street_description: String,
+    #[serde(default)]
+    settlement_street_description_ru: String,
+    streets_type: Uuid,
+    streets_type_description: String,
+}
+
+impl SettlementStreet {
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    pub fn settlement_ref(&self) -> SettlementID {
+        self.settlement_ref
+    }
+
+    pub fn street_ref(&self) -> SettlementStreetID {
+        self.settlement_street_ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.settlement_street_description
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SettlementStreets {
+    total_count: u16,
+    addresses: Vec<SettlementStreet>,
+}
+
+impl SettlementStreets {
+    pub fn total_count(&self) -> u16 {
+        self.total_count
+    }
+
+    pub fn streets(&self) -> &[SettlementStreet] {
+        &self.addresses
+    }
+}
+
+/// Street of a city as returned by `getStreet`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Street {
+    r#ref: StreetRef,
+    description: String,
+    streets_type_ref: String,
+    streets_type: String,
+}
+
+impl Street {
+    pub fn r#ref(&self) -> StreetRef {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn streets_type(&self) -> &str {
+        &self.streets_type
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchSettlementStreetsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    settlement_ref: SettlementID,
+
+    street_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u16>,
+}
+
+impl<'cli> SearchSettlementStreetsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, settlement_ref: SettlementID, street_name: String) -> Self {
+        Self {
+            client,
+            settlement_ref,
+            street_name,
+            page: None,
+            limit: None,
+        }
+    }
+
+    pub fn page(mut self, page: u16) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<SettlementStreets>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "searchSettlementStreets",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetStreetsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    city_ref: CityID,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    find_by_string: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u16>,
+}
+
+impl<'cli> GetStreetsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, city_ref: CityID) -> Self {
+        Self {
+            client,
+            city_ref,
+            find_by_string: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    pub fn find_by_string(mut self, search_val: String) -> Self {
+        self.find_by_string = Some(search_val);
+        self
+    }
+
+    pub fn page(mut self, page: u16) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<Street>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "getStreet",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
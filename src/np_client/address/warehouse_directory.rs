@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hash::Hash;
+use std::io;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+
+use crate::np_client::helper_structs::{CityID, SettlementID, WarehouseID};
+use crate::np_client::NPClient;
+use crate::np_client::res_template::ResponseTemplate;
+
+use super::warehouse_index::WarehouseIndex;
+use super::warehouses::{Warehouse, WarehouseKind};
+
+const PAGE_SIZE: u16 = 500;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DirectoryError {
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    #[error("Request rejected by the API: {0:?}")]
+    Rejected(Vec<String>),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    #[serde_as(as = "TimestampSeconds<i64>")]
+    synced_at: DateTime<Utc>,
+    warehouses: Vec<Warehouse>,
+}
+
+/// In-memory copy of all warehouses, indexed for lookups by ref, city,
+/// settlement, number and type.
+///
+/// The API has no "changed since" filter, so a refresh is always a full
+/// paginated sync that replaces the whole set.
+#[derive(Debug)]
+pub struct WarehouseDirectory {
+    synced_at: DateTime<Utc>,
+    warehouses: Vec<Warehouse>,
+    by_ref: HashMap<WarehouseID, usize>,
+    by_city: HashMap<CityID, Vec<usize>>,
+    by_settlement: HashMap<SettlementID, Vec<usize>>,
+    by_type: HashMap<WarehouseKind, Vec<usize>>,
+}
+
+impl WarehouseDirectory {
+    pub fn new(warehouses: Vec<Warehouse>, synced_at: DateTime<Utc>) -> Self {
+        let mut by_ref = HashMap::new();
+        let mut by_city = HashMap::new();
+        let mut by_settlement = HashMap::new();
+        let mut by_type = HashMap::new();
+
+        for (idx, warehouse) in warehouses.iter().enumerate() {
+            by_ref.insert(warehouse.r#ref(), idx);
+            push_index(&mut by_city, warehouse.city_ref(), idx);
+            push_index(&mut by_settlement, warehouse.settlement_ref(), idx);
+            push_index(&mut by_type, warehouse.kind(), idx);
+        }
+
+        Self {
+            synced_at,
+            warehouses,
+            by_ref,
+            by_city,
+            by_settlement,
+            by_type,
+        }
+    }
+
+    /// Downloads every warehouse page by page, until an empty page or
+    /// `info.totalCount` items.
+    pub async fn sync(client: &NPClient) -> Result<Self, DirectoryError> {
+        let mut warehouses = Vec::new();
+        let mut page = 1;
+        loop {
+            let res = client
+                .address()
+                .get_warehouses()
+                .page(page)
+                .limit(PAGE_SIZE)
+                .send()
+                .await?;
+            if !res.success {
+                return Err(DirectoryError::Rejected(res.errors));
+            }
+
+            let last = is_last_page(&res, warehouses.len());
+            warehouses.extend(res.data);
+            if last {
+                break;
+            }
+            page += 1;
+        }
+        Ok(Self::new(warehouses, Utc::now()))
+    }
+
+    /// Reads a directory written by [`Self::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DirectoryError> {
+        let snapshot: Snapshot = serde_json::from_slice(&fs::read(path)?)?;
+        Ok(Self::new(snapshot.warehouses, snapshot.synced_at))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), DirectoryError> {
+        let snapshot = SnapshotRef {
+            synced_at: self.synced_at,
+            warehouses: &self.warehouses,
+        };
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(&snapshot)?)?;
+        fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Re-syncs when the data is older than `max_age`, returning whether a
+    /// sync happened. On failure the current data is kept.
+    pub async fn refresh_if_stale(&mut self, client: &NPClient, max_age: Duration) -> Result<bool, DirectoryError> {
+        if !self.is_stale(max_age) {
+            return Ok(false);
+        }
+        *self = Self::sync(client).await?;
+        Ok(true)
+    }
+
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        Utc::now() - self.synced_at > max_age
+    }
+
+    pub fn synced_at(&self) -> DateTime<Utc> {
+        self.synced_at
+    }
+
+    pub fn len(&self) -> usize {
+        self.warehouses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.warehouses.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Warehouse> {
+        self.warehouses.iter()
+    }
+
+    /// Proximity index over the whole directory.
+    pub fn spatial_index(&self) -> WarehouseIndex<'_> {
+        WarehouseIndex::new(&self.warehouses)
+    }
+
+    pub fn get(&self, warehouse_ref: WarehouseID) -> Option<&Warehouse> {
+        self.by_ref.get(&warehouse_ref).map(|idx| &self.warehouses[*idx])
+    }
+
+    pub fn by_city(&self, city_ref: CityID) -> Vec<&Warehouse> {
+        self.lookup(&self.by_city, &city_ref)
+    }
+
+    pub fn by_settlement(&self, settlement_ref: SettlementID) -> Vec<&Warehouse> {
+        self.lookup(&self.by_settlement, &settlement_ref)
+    }
+
+    pub fn by_type(&self, kind: WarehouseKind) -> Vec<&Warehouse> {
+        self.lookup(&self.by_type, &kind)
+    }
+
+    /// Warehouse numbers are only unique within a city.
+    pub fn by_number(&self, city_ref: CityID, number: u16) -> Option<&Warehouse> {
+        self.by_city(city_ref)
+            .into_iter()
+            .find(|warehouse| warehouse.number() == number)
+    }
+
+    fn lookup<K: Eq + Hash>(&self, index: &HashMap<K, Vec<usize>>, key: &K) -> Vec<&Warehouse> {
+        index
+            .get(key)
+            .map(|ids| ids.iter().map(|idx| &self.warehouses[*idx]).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[serde_as]
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    #[serde_as(as = "TimestampSeconds<i64>")]
+    synced_at: DateTime<Utc>,
+    warehouses: &'a [Warehouse],
+}
+
+/// The API may return fewer items per page than asked for, so a short page
+/// doesn't mean the end.
+pub(crate) fn is_last_page<T>(res: &ResponseTemplate<T>, received_before: usize) -> bool {
+    let total = res.info.get("totalCount").and_then(|total| total.as_u64());
+    res.data.is_empty() || total.is_some_and(|total| (received_before + res.data.len()) as u64 >= total)
+}
+
+fn push_index<K: Eq + Hash>(index: &mut HashMap<K, Vec<usize>>, key: K, idx: usize) {
+    index.entry(key).or_default().push(idx);
+}
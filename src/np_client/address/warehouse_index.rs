@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::np_client::date_format::kyiv_now;
+use crate::np_client::helper_structs::CargoType;
+
+use super::warehouses::{ParcelSpec, Warehouse, WarehouseHours};
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+/// Grid cell size in degrees, roughly 5.5 km north to south.
+const CELL_DEG: f64 = 0.05;
+
+/// Criteria a warehouse must meet to be returned by [`WarehouseIndex`].
+#[derive(Debug, Clone, Default)]
+pub struct WarehouseFilter {
+    postomat: Option<bool>,
+    cargo_type: Option<CargoType>,
+    place_weight: Option<u16>,
+    open_at: Option<NaiveDateTime>,
+    hours: WarehouseHours,
+    pos_terminal: bool,
+    works_on_sundays: bool,
+    parcel: Option<ParcelSpec>,
+}
+
+impl WarehouseFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only postomats when `true`, only branches when `false`.
+    pub fn postomat(mut self, postomat: bool) -> Self {
+        self.postomat = Some(postomat);
+        self
+    }
+
+    pub fn accepts(mut self, cargo_type: CargoType) -> Self {
+        self.cargo_type = Some(cargo_type);
+        self
+    }
+
+    /// Seats of `weight` kg must be allowed.
+    pub fn place_weight(mut self, weight: u16) -> Self {
+        self.place_weight = Some(weight);
+        self
+    }
+
+    /// Open at the given Kyiv local time, per the schedule chosen with
+    /// [`Self::hours`].
+    pub fn open_at(mut self, at: NaiveDateTime) -> Self {
+        self.open_at = Some(at);
+        self
+    }
+
+    /// Open at the current Kyiv time, whatever the local time zone.
+    pub fn open_now(self) -> Self {
+        self.open_at(kyiv_now())
+    }
+
+    /// Schedule checked by [`Self::open_at`] and [`Self::works_on_sundays`],
+    /// defaults to the working hours.
+    pub fn hours(mut self, hours: WarehouseHours) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// The warehouse must be able to receive the parcel, see
+    /// [`Warehouse::can_accept`].
+    pub fn can_accept(mut self, parcel: ParcelSpec) -> Self {
+        self.parcel = Some(parcel);
+        self
+    }
+
+    pub fn works_on_sundays(mut self) -> Self {
+        self.works_on_sundays = true;
+        self
+    }
+
+    /// Card payment must be available.
+    pub fn pos_terminal(mut self) -> Self {
+        self.pos_terminal = true;
+        self
+    }
+
+    pub fn matches(&self, warehouse: &Warehouse) -> bool {
+        self.postomat.is_none_or(|postomat| warehouse.is_postomat() == postomat)
+            && self.cargo_type.is_none_or(|cargo_type| warehouse.accepts_cargo_type(cargo_type))
+            && self.place_weight.is_none_or(|weight| {
+                warehouse.place_max_weight_allowed().is_none_or(|max| weight <= max)
+            })
+            && self.open_at.is_none_or(|at| warehouse.hours(self.hours).is_open_at(at))
+            && (!self.pos_terminal || warehouse.pos_terminal())
+            && (!self.works_on_sundays || warehouse.hours(self.hours).works_on_sundays())
+            && self.parcel.as_ref().is_none_or(|parcel| warehouse.can_accept(parcel).is_ok())
+    }
+}
+
+/// Grid index over warehouse coordinates for proximity queries. Distances
+/// are great-circle distances in km.
+#[derive(Debug)]
+pub struct WarehouseIndex<'a> {
+    warehouses: Vec<&'a Warehouse>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl<'a> WarehouseIndex<'a> {
+    /// Warehouses without coordinates are left out.
+    pub fn new(warehouses: impl IntoIterator<Item = &'a Warehouse>) -> Self {
+        let warehouses = warehouses
+            .into_iter()
+            .filter(|warehouse| {
+                let coordinates = warehouse.coordinates();
+                coordinates.latitude() != 0.0 || coordinates.longitude() != 0.0
+            })
+            .collect::<Vec<_>>();
+
+        let mut cells: HashMap<_, Vec<_>> = HashMap::new();
+        for (idx, warehouse) in warehouses.iter().enumerate() {
+            cells.entry(cell_of(position(warehouse))).or_default().push(idx);
+        }
+        Self { warehouses, cells }
+    }
+
+    /// Up to `k` matching warehouses closest to the point, nearest first.
+    pub fn nearest(&self, lat: f64, lon: f64, k: usize, filter: &WarehouseFilter) -> Vec<(&'a Warehouse, f64)> {
+        if k == 0 || self.warehouses.is_empty() {
+            return Vec::new();
+        }
+        let (row, col) = cell_of((lat, lon));
+        let max_ring = self.max_ring((row, col));
+        let mut found = Vec::new();
+
+        for ring in 0..=max_ring {
+            for cell in ring_cells((row, col), ring) {
+                found.extend(self.matching_in_cell(cell, (lat, lon), filter));
+            }
+            found.sort_by(|a: &(&Warehouse, f64), b| a.1.total_cmp(&b.1));
+            // Anything outside the scanned rings is at least `ring` cells away.
+            if found.len() >= k && found[k - 1].1 <= ring as f64 * cell_min_km(lat) {
+                break;
+            }
+        }
+        found.truncate(k);
+        found
+    }
+
+    /// Matching warehouses within `radius_km` of the point, nearest first.
+    pub fn within_radius(&self, lat: f64, lon: f64, radius_km: f64, filter: &WarehouseFilter) -> Vec<(&'a Warehouse, f64)> {
+        let (row, col) = cell_of((lat, lon));
+        let rings = (radius_km / cell_min_km(lat)).ceil() as i32 + 1;
+        let rings = rings.min(self.max_ring((row, col)));
+
+        let mut found = (0..=rings)
+            .flat_map(|ring| ring_cells((row, col), ring))
+            .flat_map(|cell| self.matching_in_cell(cell, (lat, lon), filter))
+            .filter(|(_, distance)| *distance <= radius_km)
+            .collect::<Vec<_>>();
+        found.sort_by(|a, b| a.1.total_cmp(&b.1));
+        found
+    }
+
+    fn matching_in_cell<'s>(&'s self, cell: (i32, i32), point: (f64, f64), filter: &'s WarehouseFilter) -> impl Iterator<Item = (&'a Warehouse, f64)> + 's {
+        self.cells
+            .get(&cell)
+            .into_iter()
+            .flatten()
+            .map(|idx| self.warehouses[*idx])
+            .filter(|warehouse| filter.matches(warehouse))
+            .map(move |warehouse| (warehouse, haversine_km(point, position(warehouse))))
+    }
+
+    /// Ring count after which every occupied cell has been visited.
+    fn max_ring(&self, (row, col): (i32, i32)) -> i32 {
+        self.cells
+            .keys()
+            .map(|(r, c)| (r - row).abs().max((c - col).abs()))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn position(warehouse: &Warehouse) -> (f64, f64) {
+    let coordinates = warehouse.coordinates();
+    (coordinates.latitude() as f64, coordinates.longitude() as f64)
+}
+
+fn cell_of((lat, lon): (f64, f64)) -> (i32, i32) {
+    ((lat / CELL_DEG).floor() as i32, (lon / CELL_DEG).floor() as i32)
+}
+
+/// Cells forming the square ring `ring` cells away from the center.
+fn ring_cells((row, col): (i32, i32), ring: i32) -> Vec<(i32, i32)> {
+    if ring == 0 {
+        return vec![(row, col)];
+    }
+    let mut cells = Vec::new();
+    for d in -ring..=ring {
+        cells.push((row - ring, col + d));
+        cells.push((row + ring, col + d));
+    }
+    for d in -ring + 1..ring {
+        cells.push((row + d, col - ring));
+        cells.push((row + d, col + ring));
+    }
+    cells
+}
+
+/// Shortest side of a cell near `lat`: longitude degrees shrink towards the
+/// poles.
+fn cell_min_km(lat: f64) -> f64 {
+    let lat_km = CELL_DEG.to_radians() * EARTH_RADIUS_KM;
+    let lon_km = lat_km * (lat.abs() + CELL_DEG).min(90.0).to_radians().cos();
+    lat_km.min(lon_km)
+}
+
+fn haversine_km((lat1, lon1): (f64, f64), (lat2, lon2): (f64, f64)) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
@@ -3,30 +3,318 @@ use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString};
 use uuid::Uuid;
 
-use crate::np_client::helper_structs::Coordinates;
+use crate::np_client::helper_structs::{CargoType, CityID, Coordinates, SettlementID, WarehouseID};
 use crate::np_client::{NPClient, NPRequest};
 use crate::np_client::res_template::ResponseTemplate;
 use crate::np_client::date_format::common_date_format;
 use crate::np_client::deserializer::deserialize_bool_from_str_num;
 
+use super::schedule::Schedule;
+
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-struct Dimensions {
+pub struct Dimensions {
     width: u16,
     height: u16,
     length: u16,
 }
+
+impl Dimensions {
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Whether a box of the given size (cm) fits, allowing it to be rotated.
+    /// Zero limits mean the warehouse has no dimension restriction.
+    pub fn fits(&self, width: f32, length: f32, height: f32) -> bool {
+        self.exceeding_side(width, length, height).is_none()
+    }
+
+    /// First side of the box, with the box rotated to fit best, that is
+    /// longer than the matching limit; as `(side, limit)`.
+    pub fn exceeding_side(&self, width: f32, length: f32, height: f32) -> Option<(f32, u16)> {
+        if self.width == 0 && self.height == 0 && self.length == 0 {
+            return None;
+        }
+        let mut limits = [self.width, self.height, self.length];
+        let mut parcel = [width, length, height];
+        limits.sort();
+        parcel.sort_by(f32::total_cmp);
+
+        limits
+            .into_iter()
+            .zip(parcel)
+            .find(|(limit, side)| *side > *limit as f32)
+            .map(|(limit, side)| (side, limit))
+    }
+}
+
+/// Reason a warehouse can't take a parcel.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq)]
+pub enum ParcelRejection {
+    #[error("Parcel weight exceeds the warehouse limit of {limit} kg")]
+    TooHeavy { limit: u16 },
+    #[error("Seat {seat} weight exceeds the warehouse limit of {limit} kg")]
+    SeatTooHeavy { seat: usize, limit: u16 },
+    #[error("Seat {seat} side of {side} cm exceeds the warehouse limit of {limit} cm")]
+    SeatTooLarge { seat: usize, side: f32, limit: u16 },
+    #[error("Declared cost exceeds the warehouse limit of {limit}")]
+    DeclaredCostTooHigh { limit: f32 },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SeatSpec {
+    size: Option<[f32; 3]>,
+    weight: f32,
+}
+
+/// Parcel to check against warehouse limits. Sizes are in cm, weight in kg.
+#[derive(Debug, Clone, Default)]
+pub struct ParcelSpec {
+    seats: Vec<SeatSpec>,
+    total_weight: Option<f32>,
+    declared_cost: f32,
+}
+
+impl ParcelSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn seat(mut self, width: f32, length: f32, height: f32, weight: f32) -> Self {
+        self.seats.push(SeatSpec {
+            size: Some([width, length, height]),
+            weight,
+        });
+        self
+    }
+
+    /// Seat whose size is unknown, only its weight is checked.
+    pub fn seat_by_weight(mut self, weight: f32) -> Self {
+        self.seats.push(SeatSpec { size: None, weight });
+        self
+    }
+
+    pub fn declared_cost(mut self, declared_cost: f32) -> Self {
+        self.declared_cost = declared_cost;
+        self
+    }
+
+    /// Weight checked against the whole-parcel limit instead of the sum of
+    /// the seats, e.g. the weight declared on a waybill.
+    pub fn total_weight(mut self, weight: f32) -> Self {
+        self.total_weight = Some(weight);
+        self
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.total_weight.unwrap_or_else(|| self.seats.iter().map(|seat| seat.weight).sum())
+    }
+}
+
+/// Limits one direction of a warehouse (sending or receiving) puts on a
+/// parcel. Zero limits in the API data mean unrestricted.
+#[derive(Debug, Clone, Copy)]
+pub struct ParcelLimits {
+    dimensions: Dimensions,
+    place_max_weight: Option<u16>,
+    total_max_weight: Option<u16>,
+    max_declared_cost: Option<f32>,
+}
+
+impl ParcelLimits {
+    pub fn check(&self, parcel: &ParcelSpec) -> Result<(), Vec<ParcelRejection>> {
+        let mut rejections = Vec::new();
+
+        if let Some(limit) = self.total_max_weight {
+            if parcel.weight() > limit as f32 {
+                rejections.push(ParcelRejection::TooHeavy { limit });
+            }
+        }
+        if let Some(limit) = self.max_declared_cost {
+            if parcel.declared_cost > limit {
+                rejections.push(ParcelRejection::DeclaredCostTooHigh { limit });
+            }
+        }
+        for (seat_idx, seat) in parcel.seats.iter().enumerate() {
+            if let Some([width, length, height]) = seat.size {
+                if let Some((side, limit)) = self.dimensions.exceeding_side(width, length, height) {
+                    rejections.push(ParcelRejection::SeatTooLarge { seat: seat_idx, side, limit });
+                }
+            }
+            if let Some(limit) = self.place_max_weight {
+                if seat.weight > limit as f32 {
+                    rejections.push(ParcelRejection::SeatTooHeavy { seat: seat_idx, limit });
+                }
+            }
+        }
+
+        if rejections.is_empty() {
+            Ok(())
+        } else {
+            Err(rejections)
+        }
+    }
+}
+
+/// Warehouse type, see `AddressHandler::get_warehouse_types`. Types this
+/// crate doesn't know about are kept as `Other` with their ref.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "Uuid", into = "Uuid")]
+pub enum WarehouseKind {
+    Branch,
+    /// Takes cargo over 30 kg and pallets.
+    CargoBranch,
+    Postomat,
+    /// Parcel locker operated by PrivatBank.
+    PostomatPrivat,
+    /// Pickup point inside a store.
+    ParcelShop,
+    Other(Uuid),
+}
+
+const BRANCH: Uuid = uuid::uuid!("841339c7-591a-42e2-8233-7a0a00f0ed6f");
+const CARGO_BRANCH: Uuid = uuid::uuid!("9a68df70-0267-42a8-bb5c-37f427e36ee4");
+const POSTOMAT: Uuid = uuid::uuid!("f9316480-5f2d-425d-bc2c-ac7cd29decf0");
+const POSTOMAT_PRIVAT: Uuid = uuid::uuid!("95dc212d-479c-4ffb-a8ab-8c1b9073d0bc");
+const PARCEL_SHOP: Uuid = uuid::uuid!("6f8c7162-4b72-4b0a-88e5-906948c6a92f");
+
+impl WarehouseKind {
+    pub fn type_ref(&self) -> Uuid {
+        match self {
+            Self::Branch => BRANCH,
+            Self::CargoBranch => CARGO_BRANCH,
+            Self::Postomat => POSTOMAT,
+            Self::PostomatPrivat => POSTOMAT_PRIVAT,
+            Self::ParcelShop => PARCEL_SHOP,
+            Self::Other(type_ref) => *type_ref,
+        }
+    }
+
+    pub fn is_postomat(&self) -> bool {
+        matches!(self, Self::Postomat | Self::PostomatPrivat)
+    }
+}
+
+impl From<Uuid> for WarehouseKind {
+    fn from(type_ref: Uuid) -> Self {
+        match type_ref {
+            BRANCH => Self::Branch,
+            CARGO_BRANCH => Self::CargoBranch,
+            POSTOMAT => Self::Postomat,
+            POSTOMAT_PRIVAT => Self::PostomatPrivat,
+            PARCEL_SHOP => Self::ParcelShop,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<WarehouseKind> for Uuid {
+    fn from(kind: WarehouseKind) -> Self {
+        kind.type_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum WarehouseStatus {
+    Working,
+    NotWorking,
+    TemporarilyClosed,
+    Other(String),
+}
+
+impl From<String> for WarehouseStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Working" => Self::Working,
+            "NotWorking" => Self::NotWorking,
+            "TemporarilyClosed" | "TemporaryClosed" => Self::TemporarilyClosed,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<WarehouseStatus> for String {
+    fn from(status: WarehouseStatus) -> Self {
+        match status {
+            WarehouseStatus::Working => "Working".to_owned(),
+            WarehouseStatus::NotWorking => "NotWorking".to_owned(),
+            WarehouseStatus::TemporarilyClosed => "TemporarilyClosed".to_owned(),
+            WarehouseStatus::Other(value) => value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum WarehouseCategory {
+    Branch,
+    Postomat,
+    Store,
+    Other(String),
+}
+
+impl From<String> for WarehouseCategory {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Branch" => Self::Branch,
+            "Postomat" => Self::Postomat,
+            "Store" => Self::Store,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<WarehouseCategory> for String {
+    fn from(category: WarehouseCategory) -> Self {
+        match category {
+            WarehouseCategory::Branch => "Branch".to_owned(),
+            WarehouseCategory::Postomat => "Postomat".to_owned(),
+            WarehouseCategory::Store => "Store".to_owned(),
+            WarehouseCategory::Other(value) => value,
+        }
+    }
+}
+
+/// Entry of `Address.getWarehouseTypes`.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-struct Schedule {
-    monday: String,
-    tuesday: String,
-    wednesday: String,
-    thursday: String,
-    friday: String,
-    saturday: String,
-    sunday: String,
+pub struct WarehouseType {
+    r#ref: Uuid,
+    description: String,
+    description_ru: String,
+}
+
+impl WarehouseType {
+    pub fn kind(&self) -> WarehouseKind {
+        self.r#ref.into()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Which of a warehouse's schedules to check.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WarehouseHours {
+    /// [`Warehouse::schedule`].
+    #[default]
+    Working,
+    /// [`Warehouse::reception`].
+    Reception,
+    /// [`Warehouse::delivery`].
+    Delivery,
 }
 
 #[serde_as]
@@ -40,14 +328,14 @@ pub struct Warehouse {
     short_address: String,
     short_address_ru: String,
     phone: String,
-    type_of_warehouse: Uuid,
-    r#ref: Uuid,
+    type_of_warehouse: WarehouseKind,
+    r#ref: WarehouseID,
     #[serde_as(as = "DisplayFromStr")]
     number: u16,
-    city_ref: Uuid,
+    city_ref: CityID,
     city_description: String,
     city_description_ru: String,
-    settlement_ref: Uuid,
+    settlement_ref: SettlementID,
     settlement_description: String,
     settlement_area_description: String,
     settlement_regions_description: String,
@@ -78,10 +366,10 @@ pub struct Warehouse {
     delivery: Schedule,
     schedule: Schedule,
     district_code: String,
-    warehouse_status: String,
+    warehouse_status: WarehouseStatus,
     #[serde(with = "common_date_format")]
     warehouse_status_date: Option<NaiveDateTime>,
-    category_of_warehouse: String,
+    category_of_warehouse: WarehouseCategory,
     #[serde_as(as = "NoneAsEmptyString")]
     direct: Option<String>,
     region_city: String,
@@ -112,6 +400,155 @@ pub struct Warehouse {
     beacon_code: Option<String>,
 }
 
+impl Warehouse {
+    pub fn r#ref(&self) -> WarehouseID {
+        self.r#ref
+    }
+
+    pub fn number(&self) -> u16 {
+        self.number
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn kind(&self) -> WarehouseKind {
+        self.type_of_warehouse
+    }
+
+    pub fn status(&self) -> &WarehouseStatus {
+        &self.warehouse_status
+    }
+
+    pub fn category(&self) -> &WarehouseCategory {
+        &self.category_of_warehouse
+    }
+
+    /// E.g. `FullDayPoshtomat`, only set for postomats.
+    pub fn post_machine_type(&self) -> Option<&str> {
+        self.post_machine_type.as_deref()
+    }
+
+    pub fn city_ref(&self) -> CityID {
+        self.city_ref
+    }
+
+    pub fn coordinates(&self) -> &Coordinates {
+        &self.coordinates
+    }
+
+    pub fn is_postomat(&self) -> bool {
+        self.type_of_warehouse.is_postomat()
+    }
+
+    /// Card payment is available.
+    pub fn pos_terminal(&self) -> bool {
+        self.pos_terminal
+    }
+
+    /// Postomats take parcels and documents only, pallets need a cargo
+    /// branch.
+    pub fn accepts_cargo_type(&self, cargo_type: CargoType) -> bool {
+        match cargo_type {
+            CargoType::Parcel | CargoType::Documents => true,
+            _ if self.is_postomat() => false,
+            CargoType::Pallet => self.type_of_warehouse == WarehouseKind::CargoBranch,
+            CargoType::Cargo | CargoType::TiresWheels => true,
+        }
+    }
+
+    /// Working hours of the warehouse.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Hours when parcels are accepted for sending.
+    pub fn reception(&self) -> &Schedule {
+        &self.reception
+    }
+
+    /// Hours when parcels can be picked up.
+    pub fn delivery(&self) -> &Schedule {
+        &self.delivery
+    }
+
+    pub fn hours(&self, hours: WarehouseHours) -> &Schedule {
+        match hours {
+            WarehouseHours::Working => &self.schedule,
+            WarehouseHours::Reception => &self.reception,
+            WarehouseHours::Delivery => &self.delivery,
+        }
+    }
+
+    /// Whether the warehouse works at the given Kyiv local time.
+    pub fn is_open_at(&self, at: NaiveDateTime) -> bool {
+        self.schedule.is_open_at(at)
+    }
+
+    pub fn settlement_ref(&self) -> SettlementID {
+        self.settlement_ref
+    }
+
+    /// Weight limit for a single seat in kg, `None` when unrestricted.
+    pub fn place_max_weight_allowed(&self) -> Option<u16> {
+        Some(self.place_max_weight_allowed).filter(|w| *w > 0)
+    }
+
+    /// Weight limit for the whole parcel in kg, `None` when unrestricted.
+    pub fn total_max_weight_allowed(&self) -> Option<u16> {
+        Some(self.total_max_weight_allowed).filter(|w| *w > 0)
+    }
+
+    pub fn sending_limitations_on_dimensions(&self) -> &Dimensions {
+        &self.sending_limitations_on_dimensions
+    }
+
+    pub fn receiving_limitations_on_dimensions(&self) -> &Dimensions {
+        &self.receiving_limitations_on_dimensions
+    }
+
+    /// Declared cost limit, `None` when unrestricted.
+    pub fn max_declared_cost(&self) -> Option<f32> {
+        Some(self.max_declared_cost).filter(|c| *c > 0.0)
+    }
+
+    pub fn sending_limits(&self) -> ParcelLimits {
+        self.limits(self.sending_limitations_on_dimensions)
+    }
+
+    pub fn receiving_limits(&self) -> ParcelLimits {
+        self.limits(self.receiving_limitations_on_dimensions)
+    }
+
+    fn limits(&self, dimensions: Dimensions) -> ParcelLimits {
+        ParcelLimits {
+            dimensions,
+            place_max_weight: self.place_max_weight_allowed(),
+            total_max_weight: self.total_max_weight_allowed(),
+            max_declared_cost: self.max_declared_cost(),
+        }
+    }
+
+    /// Whether the parcel can be delivered to this warehouse; every failed
+    /// limit otherwise.
+    pub fn can_accept(&self, parcel: &ParcelSpec) -> Result<(), Vec<ParcelRejection>> {
+        self.receiving_limits().check(parcel)
+    }
+
+    /// Whether the parcel can be sent from this warehouse; every failed
+    /// limit otherwise.
+    pub fn can_send(&self, parcel: &ParcelSpec) -> Result<(), Vec<ParcelRejection>> {
+        self.sending_limits().check(parcel)
+    }
+}
+
+impl From<&Warehouse> for WarehouseID {
+    fn from(warehouse: &Warehouse) -> Self {
+        warehouse.r#ref
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GetWarehousesBuilder<'cli> {
@@ -122,7 +559,10 @@ pub struct GetWarehousesBuilder<'cli> {
     city_name: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    city_ref: Option<Uuid>,
+    city_ref: Option<CityID>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    find_by_string: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     bicycle_parking: Option<u16>,
@@ -134,7 +574,7 @@ pub struct GetWarehousesBuilder<'cli> {
     page: Option<u16>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    type_of_warehouse_ref: Option<Uuid>,
+    type_of_warehouse_ref: Option<WarehouseKind>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<u16>,
@@ -147,6 +587,7 @@ impl<'cli> GetWarehousesBuilder<'cli> {
             client,
             city_name: None,
             city_ref:None,
+            find_by_string: None,
             warehouse_id: None,
             page: None,
             limit: None,
@@ -175,16 +616,27 @@ impl<'cli> GetWarehousesBuilder<'cli> {
         self
     }
 
-    pub fn city_ref(mut self, c_ref: Uuid) -> Self {
+    pub fn city_ref(mut self, c_ref: CityID) -> Self {
         self.city_ref = Some(c_ref);
         self
     }
 
-    pub fn type_of_warehouse_ref(mut self, w_ref: Uuid) -> Self {
-        self.type_of_warehouse_ref = Some(w_ref);
+    /// Matches the warehouse description or number.
+    pub fn find_by_string(mut self, search_val: String) -> Self {
+        self.find_by_string = Some(search_val);
+        self
+    }
+
+    pub fn kind(mut self, kind: WarehouseKind) -> Self {
+        self.type_of_warehouse_ref = Some(kind);
         self
     }
 
+    #[deprecated(note = "use `kind`")]
+    pub fn type_of_warehouse_ref(self, w_ref: Uuid) -> Self {
+        self.kind(w_ref.into())
+    }
+
     pub fn bicycle_parking(mut self, bicycle_parking: u16) -> Self {
         self.bicycle_parking = Some(bicycle_parking);
         self
@@ -197,7 +649,36 @@ impl<'cli> GetWarehousesBuilder<'cli> {
             called_method: "getWarehouses",
             method_properties: &self
         };
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetWarehouseTypesBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+}
+
+impl<'cli> GetWarehouseTypesBuilder<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<WarehouseType>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "getWarehouseTypes",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
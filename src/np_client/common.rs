@@ -0,0 +1,72 @@
+use self::cargo_descriptions::GetCargoDescriptionsBuilder;
+use self::dictionaries::{GetDictionaryBuilder, OwnershipForm, Pack, Pallet, ReferenceType, TiresWheels};
+use self::time_intervals::GetTimeIntervalsBuilder;
+
+use super::NPClient;
+use super::helper_structs::CityID;
+
+pub mod cargo_descriptions;
+pub mod dictionaries;
+pub mod time_intervals;
+
+pub struct CommonHandler<'c> {
+    client: &'c NPClient,
+}
+
+impl<'cli> CommonHandler<'cli> {
+    pub(crate) fn new(client: &'cli NPClient) -> Self {
+        Self { client }
+    }
+
+    pub fn get_cargo_types(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getCargoTypes")
+    }
+
+    pub fn get_service_types(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getServiceTypes")
+    }
+
+    pub fn get_types_of_payers(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getTypesOfPayers")
+    }
+
+    pub fn get_types_of_payers_for_redelivery(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getTypesOfPayersForRedelivery")
+    }
+
+    pub fn get_types_of_counterparties(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getTypesOfCounterparties")
+    }
+
+    pub fn get_payment_forms(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getPaymentForms")
+    }
+
+    pub fn get_backward_delivery_cargo_types(&self) -> GetDictionaryBuilder<'cli, ReferenceType> {
+        GetDictionaryBuilder::new(self.client, "getBackwardDeliveryCargoTypes")
+    }
+
+    pub fn get_ownership_forms(&self) -> GetDictionaryBuilder<'cli, OwnershipForm> {
+        GetDictionaryBuilder::new(self.client, "getOwnershipFormsList")
+    }
+
+    pub fn get_pallets(&self) -> GetDictionaryBuilder<'cli, Pallet> {
+        GetDictionaryBuilder::new(self.client, "getPalletsList")
+    }
+
+    pub fn get_packs(&self) -> GetDictionaryBuilder<'cli, Pack> {
+        GetDictionaryBuilder::new(self.client, "getPackList")
+    }
+
+    pub fn get_tires_wheels(&self) -> GetDictionaryBuilder<'cli, TiresWheels> {
+        GetDictionaryBuilder::new(self.client, "getTiresWheelsList")
+    }
+
+    pub fn get_time_intervals(&self, recipient_city_ref: CityID) -> GetTimeIntervalsBuilder<'cli> {
+        GetTimeIntervalsBuilder::new(self.client, recipient_city_ref)
+    }
+
+    pub fn get_cargo_descriptions(&self) -> GetCargoDescriptionsBuilder<'cli> {
+        GetCargoDescriptionsBuilder::new(self.client)
+    }
+}
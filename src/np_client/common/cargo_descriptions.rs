@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CargoDescription {
+    r#ref: Uuid,
+    description: String,
+    description_ru: String,
+}
+
+impl CargoDescription {
+    pub fn r#ref(&self) -> Uuid {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetCargoDescriptionsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    find_by_string: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u16>,
+}
+
+impl<'cli> GetCargoDescriptionsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self {
+            client,
+            find_by_string: None,
+            page: None,
+        }
+    }
+
+    pub fn find_by_string(mut self, val: String) -> Self {
+        self.find_by_string = Some(val);
+        self
+    }
+
+    pub fn page(mut self, page: u16) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<CargoDescription>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Common",
+            called_method: "getCargoDescriptionList",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
@@ -0,0 +1,209 @@
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use uuid::Uuid;
+
+use crate::np_client::deserializer::deserialize_f32_option;
+use crate::np_client::helper_structs::{OwnershipFormID, PackID};
+use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
+
+/// Entry of the string-keyed Common dictionaries (cargo types, service types,
+/// payer types, payment forms, ...). The `Ref` of each entry matches one of the
+/// enums in `helper_structs` and can be checked with [`ReferenceType::parse`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReferenceType {
+    r#ref: String,
+    description: String,
+}
+
+impl ReferenceType {
+    pub fn r#ref(&self) -> &str {
+        &self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn parse<'a, T>(&'a self) -> Result<T, T::Error>
+    where
+        T: TryFrom<&'a str>,
+    {
+        T::try_from(self.r#ref.as_str())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OwnershipForm {
+    r#ref: OwnershipFormID,
+    description: String,
+    full_name: String,
+}
+
+impl OwnershipForm {
+    pub fn r#ref(&self) -> OwnershipFormID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Pallet {
+    r#ref: Uuid,
+    description: String,
+    description_ru: String,
+    #[serde(deserialize_with = "deserialize_f32_option")]
+    weight: Option<f32>,
+}
+
+impl Pallet {
+    pub fn r#ref(&self) -> Uuid {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_ru(&self) -> &str {
+        &self.description_ru
+    }
+
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Pack {
+    r#ref: PackID,
+    description: String,
+    description_ru: String,
+    #[serde(deserialize_with = "deserialize_f32_option")]
+    length: Option<f32>,
+    #[serde(deserialize_with = "deserialize_f32_option")]
+    width: Option<f32>,
+    #[serde(deserialize_with = "deserialize_f32_option")]
+    height: Option<f32>,
+    #[serde(deserialize_with = "deserialize_f32_option")]
+    volumetric_weight: Option<f32>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    type_of_packing: Option<String>,
+}
+
+impl Pack {
+    pub fn r#ref(&self) -> PackID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_ru(&self) -> &str {
+        &self.description_ru
+    }
+
+    /// Length, width and height in mm.
+    pub fn dimensions(&self) -> (Option<f32>, Option<f32>, Option<f32>) {
+        (self.length, self.width, self.height)
+    }
+
+    pub fn volumetric_weight(&self) -> Option<f32> {
+        self.volumetric_weight
+    }
+
+    pub fn type_of_packing(&self) -> Option<&str> {
+        self.type_of_packing.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TiresWheels {
+    r#ref: Uuid,
+    description: String,
+    description_ru: String,
+    #[serde(deserialize_with = "deserialize_f32_option")]
+    weight: Option<f32>,
+    description_type: String,
+}
+
+impl TiresWheels {
+    pub fn r#ref(&self) -> Uuid {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_ru(&self) -> &str {
+        &self.description_ru
+    }
+
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+
+    /// `Tires` or `Wheels`.
+    pub fn description_type(&self) -> &str {
+        &self.description_type
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetDictionaryBuilder<'cli, T> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(skip)]
+    called_method: &'static str,
+
+    #[serde(skip)]
+    entry: PhantomData<T>,
+}
+
+impl<'cli, T> GetDictionaryBuilder<'cli, T>
+where
+    T: DeserializeOwned,
+{
+    pub fn new(client: &'cli NPClient, called_method: &'static str) -> Self {
+        Self {
+            client,
+            called_method,
+            entry: PhantomData,
+        }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<T>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Common",
+            called_method: self.called_method,
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
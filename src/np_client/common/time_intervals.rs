@@ -0,0 +1,75 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::np_client::date_format::{id_date_format_option, time_format};
+use crate::np_client::helper_structs::{CityID, TimeIntervalCode};
+use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeInterval {
+    number: TimeIntervalCode,
+    #[serde(with = "time_format")]
+    start: NaiveTime,
+    #[serde(with = "time_format")]
+    end: NaiveTime,
+}
+
+impl TimeInterval {
+    pub fn code(&self) -> &TimeIntervalCode {
+        &self.number
+    }
+
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveTime {
+        self.end
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetTimeIntervalsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    recipient_city_ref: CityID,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "id_date_format_option")]
+    date_time: Option<NaiveDate>,
+}
+
+impl<'cli> GetTimeIntervalsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, recipient_city_ref: CityID) -> Self {
+        Self {
+            client,
+            recipient_city_ref,
+            date_time: None,
+        }
+    }
+
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date_time = Some(date);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<TimeInterval>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Common",
+            called_method: "getTimeIntervals",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
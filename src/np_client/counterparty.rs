@@ -1,24 +1,33 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 use self::addresses::GetAddressesBuilder;
 use self::contact_persons::{ContactPerson, GetContactPersonsBuilder};
 use self::counterparties::GetCounterpartiesBuilder;
 use self::create::{CreateCounterpartyBuilder, NoName, NoPhone, NoType, NoRole};
+use self::create_organization::{CreateOrganizationBuilder, NoEdrpou, NoOwnershipForm};
+use self::options::GetCounterpartyOptionsBuilder;
+use self::save_address::{CreateAddressBuilder, DeleteAddressBuilder, UpdateAddressBuilder};
+use self::save_contact_person::{ContactRef, DeleteContactPersonBuilder, NoContactRef, SaveContactPersonBuilder};
+use self::update::{DeleteCounterpartyBuilder, UpdateCounterpartyBuilder};
 
 use super::NPClient;
-use super::helper_structs::CounterpartyType;
+use super::helper_structs::{AddressID, ContactPersonID, CounterpartyID, CounterpartyRole, CounterpartyType, StreetRef};
 use super::res_template::ResponseTemplate;
 
 mod addresses;
 mod contact_persons;
 mod create;
+mod create_organization;
 mod counterparties;
+mod options;
+mod save_address;
+mod save_contact_person;
+mod update;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Counterparty {
-    r#ref: Uuid,
+    r#ref: CounterpartyID,
     description: String,
     first_name: String,
     middle_name: String,
@@ -34,6 +43,26 @@ pub struct Counterparty {
     city: Option<String>,
 }
 
+impl Counterparty {
+    pub fn counterparty_id(&self) -> CounterpartyID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn counterparty_type(&self) -> CounterpartyType {
+        self.counterparty_type
+    }
+}
+
+impl From<&Counterparty> for CounterpartyID {
+    fn from(counterparty: &Counterparty) -> Self {
+        counterparty.r#ref
+    }
+}
+
 pub struct CounterpartyHandler<'c> {
     client: &'c NPClient,
 }
@@ -45,7 +74,7 @@ impl<'cli> CounterpartyHandler<'cli> {
         }
     }
 
-    pub fn get_addresses(&self, cp_ref: Uuid) -> GetAddressesBuilder<'cli> {
+    pub fn get_addresses(&self, cp_ref: CounterpartyID) -> GetAddressesBuilder<'cli> {
         GetAddressesBuilder::new(self.client, cp_ref)
     }
 
@@ -53,7 +82,7 @@ impl<'cli> CounterpartyHandler<'cli> {
         GetCounterpartiesBuilder::new(self.client)
     }
 
-    pub fn get_contact_persons(&self, cp_ref: Uuid) -> GetContactPersonsBuilder<'cli> {
+    pub fn get_contact_persons(&self, cp_ref: CounterpartyID) -> GetContactPersonsBuilder<'cli> {
         GetContactPersonsBuilder::new(self.client, cp_ref)
     }
 
@@ -61,4 +90,59 @@ impl<'cli> CounterpartyHandler<'cli> {
         CreateCounterpartyBuilder::new(self.client)
     }
 
+    pub fn create_organization(&self) -> CreateOrganizationBuilder<'cli, NoEdrpou, NoOwnershipForm, NoRole> {
+        CreateOrganizationBuilder::new(self.client)
+    }
+
+    pub fn update_counterparty(
+        &self,
+        cp_ref: CounterpartyID,
+        role: CounterpartyRole,
+        ty: CounterpartyType,
+    ) -> UpdateCounterpartyBuilder<'cli, NoName, NoPhone> {
+        UpdateCounterpartyBuilder::new(self.client, cp_ref, role, ty)
+    }
+
+    pub fn delete_counterparty(&self, cp_ref: CounterpartyID) -> DeleteCounterpartyBuilder<'cli> {
+        DeleteCounterpartyBuilder::new(self.client, cp_ref)
+    }
+
+    pub fn get_counterparty_options(&self, cp_ref: CounterpartyID) -> GetCounterpartyOptionsBuilder<'cli> {
+        GetCounterpartyOptionsBuilder::new(self.client, cp_ref)
+    }
+
+    pub fn create_address(&self, cp_ref: CounterpartyID, street_ref: StreetRef, building_number: String) -> CreateAddressBuilder<'cli> {
+        CreateAddressBuilder::new(self.client, cp_ref, street_ref, building_number)
+    }
+
+    pub fn update_address(
+        &self,
+        address_ref: AddressID,
+        cp_ref: CounterpartyID,
+        street_ref: StreetRef,
+        building_number: String,
+    ) -> UpdateAddressBuilder<'cli> {
+        UpdateAddressBuilder::new(self.client, address_ref, cp_ref, street_ref, building_number)
+    }
+
+    pub fn delete_address(&self, address_ref: AddressID) -> DeleteAddressBuilder<'cli> {
+        DeleteAddressBuilder::new(self.client, address_ref)
+    }
+
+    pub fn create_contact_person(&self, cp_ref: CounterpartyID) -> SaveContactPersonBuilder<'cli, NoContactRef, NoName, NoPhone> {
+        SaveContactPersonBuilder::new(self.client, cp_ref)
+    }
+
+    pub fn update_contact_person(
+        &self,
+        cp_ref: CounterpartyID,
+        contact_ref: ContactPersonID,
+    ) -> SaveContactPersonBuilder<'cli, ContactRef, NoName, NoPhone> {
+        SaveContactPersonBuilder::update(self.client, cp_ref, contact_ref)
+    }
+
+    pub fn delete_contact_person(&self, contact_ref: ContactPersonID) -> DeleteContactPersonBuilder<'cli> {
+        DeleteContactPersonBuilder::new(self.client, contact_ref)
+    }
+
 }
\ No newline at end of file
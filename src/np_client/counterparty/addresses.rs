@@ -1,16 +1,16 @@
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 
-use crate::np_client::{NPClient, res_template::ResponseTemplate, helper_structs::CounterpartyRole, NPRequest};
+use crate::np_client::{NPClient, res_template::ResponseTemplate, helper_structs::{AddressID, CityID, CounterpartyID, CounterpartyRole, StreetRef}, NPRequest};
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct CounterpartyAddress {
-    r#ref: Uuid,
+    r#ref: AddressID,
     description: String,
-    city_ref: Uuid,
+    city_ref: CityID,
     city_description: String,
-    street_ref: Uuid,
+    street_ref: StreetRef,
     street_description: String,
     building_ref: Uuid,
     building_description: String,
@@ -18,13 +18,33 @@ pub struct CounterpartyAddress {
     address_name: String,
 }
 
+impl CounterpartyAddress {
+    pub fn address_id(&self) -> AddressID {
+        self.r#ref
+    }
+
+    pub fn city_ref(&self) -> CityID {
+        self.city_ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl From<&CounterpartyAddress> for AddressID {
+    fn from(address: &CounterpartyAddress) -> Self {
+        address.r#ref
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GetAddressesBuilder<'cli> {
     #[serde(skip)]
     client: &'cli NPClient,
 
-    counterparty_ref: Uuid,
+    counterparty_ref: CounterpartyID,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     counterparty_property: Option<CounterpartyRole>,
@@ -32,7 +52,7 @@ pub struct GetAddressesBuilder<'cli> {
 
 
 impl<'cli> GetAddressesBuilder<'cli> {
-    pub fn new(client: &'cli NPClient, counterparty_ref: Uuid) -> Self {
+    pub fn new(client: &'cli NPClient, counterparty_ref: CounterpartyID) -> Self {
         Self {
             client,
             counterparty_ref,
@@ -52,7 +72,7 @@ impl<'cli> GetAddressesBuilder<'cli> {
             called_method: "getCounterpartyAddresses",
             method_properties: &self
         };
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
@@ -1,12 +1,11 @@
 use serde::{Serialize, Deserialize};
-use uuid::Uuid;
 
-use crate::np_client::{NPClient, res_template::ResponseTemplate, NPRequest};
+use crate::np_client::{NPClient, res_template::ResponseTemplate, helper_structs::{ContactPersonID, CounterpartyID}, NPRequest};
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ContactPerson {
-    r#ref: Uuid,
+    r#ref: ContactPersonID,
     description: String,
     first_name: String,
     middle_name: String,
@@ -15,13 +14,33 @@ pub struct ContactPerson {
     email: Option<String>,
 }
 
+impl ContactPerson {
+    pub fn contact_person_id(&self) -> ContactPersonID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn phones(&self) -> Option<&str> {
+        self.phones.as_deref()
+    }
+}
+
+impl From<&ContactPerson> for ContactPersonID {
+    fn from(contact_person: &ContactPerson) -> Self {
+        contact_person.r#ref
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GetContactPersonsBuilder<'cli> {
     #[serde(skip)]
     client: &'cli NPClient,
 
-    counterparty_ref: Uuid,
+    counterparty_ref: CounterpartyID,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u16>,
@@ -29,7 +48,7 @@ pub struct GetContactPersonsBuilder<'cli> {
 
 
 impl<'cli> GetContactPersonsBuilder<'cli> {
-    pub fn new(client: &'cli NPClient, counterparty_ref: Uuid) -> Self {
+    pub fn new(client: &'cli NPClient, counterparty_ref: CounterpartyID) -> Self {
         Self {
             client,
             counterparty_ref,
@@ -49,7 +68,7 @@ impl<'cli> GetContactPersonsBuilder<'cli> {
             called_method: "getCounterpartyContactPersons",
             method_properties: &self
         };
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
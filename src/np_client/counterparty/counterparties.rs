@@ -58,7 +58,7 @@ impl<'cli> GetCounterpartiesBuilder<'cli> {
             called_method: "getCounterparties",
             method_properties: &self
         };
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::np_client::{NPClient, res_template::ResponseTemplate, helper_structs::{CounterpartyRole, CounterpartyType}, NPRequest};
+use crate::np_client::{NPClient, res_template::ResponseTemplate, helper_structs::{CounterpartyRole, CounterpartyType}, phone::UaPhone, NPRequest};
 
 use super::Counterparty;
 
@@ -30,8 +30,26 @@ pub struct Name {
     middle_name: String,
     last_name: String,
 }
+
+impl Name {
+    pub(super) fn new(first_name: String, middle_name: String, last_name: String) -> Self {
+        Self {
+            first_name,
+            middle_name,
+            last_name,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
-pub struct Phone(String);
+pub struct Phone(UaPhone);
+
+impl Phone {
+    pub(super) fn new(phone: UaPhone) -> Self {
+        Self(phone)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct NoName {}
 #[derive(Debug, Serialize)]
@@ -81,7 +99,7 @@ impl<'cli, N, R, T> CreateCounterpartyBuilder<'cli, N, NoPhone, R, T>
         N: Serialize,
         R: Serialize,
 {
-    pub fn phone(self, phone: String) -> CreateCounterpartyBuilder<'cli, N, Phone, R, T> {
+    pub fn phone(self, phone: UaPhone) -> CreateCounterpartyBuilder<'cli, N, Phone, R, T> {
         CreateCounterpartyBuilder {
             client: self.client,
             name: self.name,
@@ -149,7 +167,7 @@ impl<'cli> CreateCounterpartyBuilder<'cli, Name, Phone, CounterpartyRole, Counte
             method_properties: &self
         };
 
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
@@ -0,0 +1,126 @@
+use serde::Serialize;
+
+use crate::np_client::{
+    NPClient,
+    res_template::ResponseTemplate,
+    edrpou::Edrpou,
+    helper_structs::{CityID, CounterpartyRole, CounterpartyType, OwnershipFormID},
+    NPRequest
+};
+
+use super::Counterparty;
+use super::create::NoRole;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateOrganizationBuilder<'cli, Edrpou, OwnershipForm, CounterpartyRole> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(rename = "EDRPOU")]
+    edrpou: Edrpou,
+
+    ownership_form: OwnershipForm,
+
+    counterparty_property: CounterpartyRole,
+
+    counterparty_type: CounterpartyType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city_ref: Option<CityID>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NoEdrpou {}
+
+#[derive(Debug, Serialize)]
+pub struct NoOwnershipForm {}
+
+impl<'cli> CreateOrganizationBuilder<'cli, NoEdrpou, NoOwnershipForm, NoRole> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self {
+            client,
+            edrpou: NoEdrpou {},
+            ownership_form: NoOwnershipForm {},
+            counterparty_property: NoRole {},
+            counterparty_type: CounterpartyType::Organization,
+            city_ref: None,
+        }
+    }
+}
+
+impl<'cli, O, R> CreateOrganizationBuilder<'cli, NoEdrpou, O, R>
+    where O: Serialize,
+        R: Serialize,
+{
+    pub fn edrpou(self, edrpou: Edrpou) -> CreateOrganizationBuilder<'cli, Edrpou, O, R> {
+        CreateOrganizationBuilder {
+            client: self.client,
+            edrpou,
+            ownership_form: self.ownership_form,
+            counterparty_property: self.counterparty_property,
+            counterparty_type: self.counterparty_type,
+            city_ref: self.city_ref,
+        }
+    }
+}
+
+impl<'cli, E, R> CreateOrganizationBuilder<'cli, E, NoOwnershipForm, R>
+    where E: Serialize,
+        R: Serialize,
+{
+    /// Ref of the ownership form, see `CommonHandler::get_ownership_forms`.
+    pub fn ownership_form(self, ownership_form: OwnershipFormID) -> CreateOrganizationBuilder<'cli, E, OwnershipFormID, R> {
+        CreateOrganizationBuilder {
+            client: self.client,
+            edrpou: self.edrpou,
+            ownership_form,
+            counterparty_property: self.counterparty_property,
+            counterparty_type: self.counterparty_type,
+            city_ref: self.city_ref,
+        }
+    }
+}
+
+impl<'cli, E, O> CreateOrganizationBuilder<'cli, E, O, NoRole>
+    where E: Serialize,
+        O: Serialize,
+{
+    pub fn role(self, role: CounterpartyRole) -> CreateOrganizationBuilder<'cli, E, O, CounterpartyRole> {
+        CreateOrganizationBuilder {
+            client: self.client,
+            edrpou: self.edrpou,
+            ownership_form: self.ownership_form,
+            counterparty_property: role,
+            counterparty_type: self.counterparty_type,
+            city_ref: self.city_ref,
+        }
+    }
+}
+
+impl<'cli, E, O, R> CreateOrganizationBuilder<'cli, E, O, R> {
+    pub fn city_ref(mut self, city_ref: CityID) -> Self {
+        self.city_ref = Some(city_ref);
+        self
+    }
+}
+
+impl<'cli> CreateOrganizationBuilder<'cli, Edrpou, OwnershipFormID, CounterpartyRole> {
+    pub async fn send(self) -> Result<ResponseTemplate<Counterparty>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Counterparty",
+            called_method: "save",
+            method_properties: &self
+        };
+
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
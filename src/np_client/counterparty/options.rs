@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::np_client::{
+    NPClient,
+    res_template::ResponseTemplate,
+    helper_structs::CounterpartyID,
+    NPRequest
+};
+
+/// Services available to a counterparty, as returned by `getCounterpartyOptions`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CounterpartyOptions {
+    filling_warranty: bool,
+    address_doc_delivery: bool,
+    clearing_account: bool,
+    customer_return: bool,
+    day_delivery: bool,
+    same_day_delivery: bool,
+    inter_terminals_delivery: bool,
+    #[serde(alias = "Loyalty")]
+    loyalty_card: bool,
+    have_money_wallets: bool,
+    debtor: bool,
+    can_afterpayment_on_goods_cost: bool,
+    can_non_cash_payment: bool,
+    can_pay_the_third_person: bool,
+    can_same_day_delivery: bool,
+    can_forwarding_service: bool,
+}
+
+impl CounterpartyOptions {
+    pub fn can_non_cash_payment(&self) -> bool {
+        self.can_non_cash_payment
+    }
+
+    pub fn can_afterpayment_on_goods_cost(&self) -> bool {
+        self.can_afterpayment_on_goods_cost
+    }
+
+    pub fn can_pay_the_third_person(&self) -> bool {
+        self.can_pay_the_third_person
+    }
+
+    pub fn can_same_day_delivery(&self) -> bool {
+        self.can_same_day_delivery
+    }
+
+    pub fn has_loyalty_card(&self) -> bool {
+        self.loyalty_card
+    }
+
+    pub fn is_debtor(&self) -> bool {
+        self.debtor
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetCounterpartyOptionsBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    r#ref: CounterpartyID,
+}
+
+impl<'cli> GetCounterpartyOptionsBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, cp_ref: CounterpartyID) -> Self {
+        Self {
+            client,
+            r#ref: cp_ref,
+        }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<CounterpartyOptions>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Counterparty",
+            called_method: "getCounterpartyOptions",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::np_client::{
+    NPClient,
+    res_template::ResponseTemplate,
+    helper_structs::{AddressID, CounterpartyID, StreetRef},
+    NPRequest
+};
+
+/// Address returned by `Address.save` and `Address.update`.
+/// Its ref can be passed to `CreateIDocumentBuilder::recipient_info` as is.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SavedAddress {
+    r#ref: AddressID,
+    #[serde(default)]
+    description: String,
+}
+
+impl SavedAddress {
+    pub fn address_id(&self) -> AddressID {
+        self.r#ref
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl From<&SavedAddress> for AddressID {
+    fn from(address: &SavedAddress) -> Self {
+        address.r#ref
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AddressFields {
+    counterparty_ref: CounterpartyID,
+    street_ref: StreetRef,
+    building_number: String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    flat: Option<String>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateAddressBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(flatten)]
+    fields: AddressFields,
+}
+
+impl<'cli> CreateAddressBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, counterparty_ref: CounterpartyID, street_ref: StreetRef, building_number: String) -> Self {
+        Self {
+            client,
+            fields: AddressFields {
+                counterparty_ref,
+                street_ref,
+                building_number,
+                flat: None,
+                note: None,
+            },
+        }
+    }
+
+    pub fn flat(mut self, flat: String) -> Self {
+        self.fields.flat = Some(flat);
+        self
+    }
+
+    pub fn note(mut self, note: String) -> Self {
+        self.fields.note = Some(note);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<SavedAddress>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "save",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateAddressBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    r#ref: AddressID,
+
+    #[serde(flatten)]
+    fields: AddressFields,
+}
+
+impl<'cli> UpdateAddressBuilder<'cli> {
+    pub fn new(
+        client: &'cli NPClient,
+        address_ref: AddressID,
+        counterparty_ref: CounterpartyID,
+        street_ref: StreetRef,
+        building_number: String,
+    ) -> Self {
+        Self {
+            client,
+            r#ref: address_ref,
+            fields: AddressFields {
+                counterparty_ref,
+                street_ref,
+                building_number,
+                flat: None,
+                note: None,
+            },
+        }
+    }
+
+    pub fn flat(mut self, flat: String) -> Self {
+        self.fields.flat = Some(flat);
+        self
+    }
+
+    pub fn note(mut self, note: String) -> Self {
+        self.fields.note = Some(note);
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<SavedAddress>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "update",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteAddressBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    r#ref: AddressID,
+}
+
+impl<'cli> DeleteAddressBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, address_ref: AddressID) -> Self {
+        Self {
+            client,
+            r#ref: address_ref,
+        }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<serde_json::Value>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Address",
+            called_method: "delete",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
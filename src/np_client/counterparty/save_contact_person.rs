@@ -0,0 +1,176 @@
+use serde::Serialize;
+
+use crate::np_client::{
+    NPClient,
+    res_template::ResponseTemplate,
+    helper_structs::{ContactPersonID, CounterpartyID},
+    phone::UaPhone,
+    NPRequest
+};
+
+use super::contact_persons::ContactPerson;
+use super::create::{Name, NoName, NoPhone, Phone};
+
+/// Builder for `ContactPerson.save` (with `NoContactRef`) and
+/// `ContactPerson.update` (with `ContactRef`).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SaveContactPersonBuilder<'cli, ContactRef, Name, Phone> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(flatten)]
+    contact_ref: ContactRef,
+
+    counterparty_ref: CounterpartyID,
+
+    #[serde(flatten)]
+    name: Name,
+
+    phone: Phone,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NoContactRef {}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactRef {
+    r#ref: ContactPersonID,
+}
+
+impl<'cli> SaveContactPersonBuilder<'cli, NoContactRef, NoName, NoPhone> {
+    pub fn new(client: &'cli NPClient, counterparty_ref: CounterpartyID) -> Self {
+        Self {
+            client,
+            contact_ref: NoContactRef {},
+            counterparty_ref,
+            name: NoName {},
+            phone: NoPhone {},
+            email: None,
+        }
+    }
+}
+
+impl<'cli> SaveContactPersonBuilder<'cli, ContactRef, NoName, NoPhone> {
+    pub fn update(client: &'cli NPClient, counterparty_ref: CounterpartyID, contact_ref: ContactPersonID) -> Self {
+        Self {
+            client,
+            contact_ref: ContactRef { r#ref: contact_ref },
+            counterparty_ref,
+            name: NoName {},
+            phone: NoPhone {},
+            email: None,
+        }
+    }
+}
+
+impl<'cli, C, P> SaveContactPersonBuilder<'cli, C, NoName, P>
+    where C: Serialize,
+        P: Serialize,
+{
+    pub fn name(self, first_name: String, middle_name: String, last_name: String) -> SaveContactPersonBuilder<'cli, C, Name, P> {
+        SaveContactPersonBuilder {
+            client: self.client,
+            contact_ref: self.contact_ref,
+            counterparty_ref: self.counterparty_ref,
+            name: Name::new(first_name, middle_name, last_name),
+            phone: self.phone,
+            email: self.email,
+        }
+    }
+}
+
+impl<'cli, C, N> SaveContactPersonBuilder<'cli, C, N, NoPhone>
+    where C: Serialize,
+        N: Serialize,
+{
+    pub fn phone(self, phone: UaPhone) -> SaveContactPersonBuilder<'cli, C, N, Phone> {
+        SaveContactPersonBuilder {
+            client: self.client,
+            contact_ref: self.contact_ref,
+            counterparty_ref: self.counterparty_ref,
+            name: self.name,
+            phone: Phone::new(phone),
+            email: self.email,
+        }
+    }
+}
+
+impl<'cli, C, N, P> SaveContactPersonBuilder<'cli, C, N, P> {
+    pub fn email(mut self, email: String) -> Self {
+        self.email = Some(email);
+        self
+    }
+}
+
+impl<'cli> SaveContactPersonBuilder<'cli, NoContactRef, Name, Phone> {
+    pub async fn send(self) -> Result<ResponseTemplate<ContactPerson>, reqwest::Error> {
+        self.send_with("save").await
+    }
+}
+
+impl<'cli> SaveContactPersonBuilder<'cli, ContactRef, Name, Phone> {
+    pub async fn send(self) -> Result<ResponseTemplate<ContactPerson>, reqwest::Error> {
+        self.send_with("update").await
+    }
+}
+
+impl<'cli, C> SaveContactPersonBuilder<'cli, C, Name, Phone>
+    where C: Serialize,
+{
+    async fn send_with(self, called_method: &str) -> Result<ResponseTemplate<ContactPerson>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "ContactPerson",
+            called_method,
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteContactPersonBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    r#ref: ContactPersonID,
+}
+
+impl<'cli> DeleteContactPersonBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, contact_ref: ContactPersonID) -> Self {
+        Self {
+            client,
+            r#ref: contact_ref,
+        }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<serde_json::Value>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "ContactPerson",
+            called_method: "delete",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
@@ -0,0 +1,160 @@
+use serde::Serialize;
+
+use crate::np_client::{
+    NPClient,
+    res_template::ResponseTemplate,
+    helper_structs::{CityID, CounterpartyID, CounterpartyRole, CounterpartyType},
+    phone::UaPhone,
+    NPRequest
+};
+
+use super::Counterparty;
+use super::create::{Name, NoName, NoPhone, Phone};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateCounterpartyBuilder<'cli, Name, Phone> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    r#ref: CounterpartyID,
+
+    #[serde(flatten)]
+    name: Name,
+
+    phone: Phone,
+
+    counterparty_property: CounterpartyRole,
+
+    counterparty_type: CounterpartyType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city_ref: Option<CityID>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+}
+
+impl<'cli> UpdateCounterpartyBuilder<'cli, NoName, NoPhone> {
+    pub fn new(
+        client: &'cli NPClient,
+        cp_ref: CounterpartyID,
+        role: CounterpartyRole,
+        ty: CounterpartyType,
+    ) -> Self {
+        Self {
+            client,
+            r#ref: cp_ref,
+            name: NoName {},
+            phone: NoPhone {},
+            counterparty_property: role,
+            counterparty_type: ty,
+            city_ref: None,
+            email: None,
+        }
+    }
+}
+
+impl<'cli, P> UpdateCounterpartyBuilder<'cli, NoName, P>
+    where P: Serialize,
+{
+    pub fn name(self, first_name: String, middle_name: String, last_name: String) -> UpdateCounterpartyBuilder<'cli, Name, P> {
+        UpdateCounterpartyBuilder {
+            client: self.client,
+            r#ref: self.r#ref,
+            name: Name::new(first_name, middle_name, last_name),
+            phone: self.phone,
+            counterparty_property: self.counterparty_property,
+            counterparty_type: self.counterparty_type,
+            city_ref: self.city_ref,
+            email: self.email,
+        }
+    }
+}
+
+impl<'cli, N> UpdateCounterpartyBuilder<'cli, N, NoPhone>
+    where N: Serialize,
+{
+    pub fn phone(self, phone: UaPhone) -> UpdateCounterpartyBuilder<'cli, N, Phone> {
+        UpdateCounterpartyBuilder {
+            client: self.client,
+            r#ref: self.r#ref,
+            name: self.name,
+            phone: Phone::new(phone),
+            counterparty_property: self.counterparty_property,
+            counterparty_type: self.counterparty_type,
+            city_ref: self.city_ref,
+            email: self.email,
+        }
+    }
+}
+
+impl<'cli, N, P> UpdateCounterpartyBuilder<'cli, N, P> {
+    pub fn city_ref(mut self, city_ref: CityID) -> Self {
+        self.city_ref = Some(city_ref);
+        self
+    }
+
+    pub fn email(mut self, email: String) -> Self {
+        self.email = Some(email);
+        self
+    }
+}
+
+impl<'cli, N> UpdateCounterpartyBuilder<'cli, N, Phone>
+    where N: Serialize,
+{
+    /// The name is left out for organizations, whose name is tied to their
+    /// EDRPOU record.
+    pub async fn send(self) -> Result<ResponseTemplate<Counterparty>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Counterparty",
+            called_method: "update",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteCounterpartyBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    r#ref: CounterpartyID,
+}
+
+impl<'cli> DeleteCounterpartyBuilder<'cli> {
+    pub fn new(client: &'cli NPClient, cp_ref: CounterpartyID) -> Self {
+        Self {
+            client,
+            r#ref: cp_ref,
+        }
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<serde_json::Value>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "Counterparty",
+            called_method: "delete",
+            method_properties: &self
+        };
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
@@ -2,11 +2,11 @@ pub mod common_date_format {
     use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
     use serde::{self, Deserialize, Deserializer, Serializer};
 
-    const FORMAT1: &'static str = "%Y-%m-%d %H:%M:%S";
-    const FORMAT2: &'static str = "%d.%m.%Y %H:%M:%S";
-    const FORMAT3: &'static str = "%d-%m-%Y %H:%M:%S";
-    const FORMAT4: &'static str = "%H:%M %d.%m.%Y";
-    const FORMAT5: &'static str = "%Y-%m-%d";
+    const FORMAT1: &str = "%Y-%m-%d %H:%M:%S";
+    const FORMAT2: &str = "%d.%m.%Y %H:%M:%S";
+    const FORMAT3: &str = "%d-%m-%Y %H:%M:%S";
+    const FORMAT4: &str = "%H:%M %d.%m.%Y";
+    const FORMAT5: &str = "%Y-%m-%d";
 
     pub fn serialize<S>(date: &Option<NaiveDateTime>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -31,15 +31,14 @@ pub mod common_date_format {
             return Ok(None);
         }
 
-        let res = NaiveDateTime::parse_from_str(&s, FORMAT1)
+        NaiveDateTime::parse_from_str(&s, FORMAT1)
             .or(NaiveDateTime::parse_from_str(&s, FORMAT2))
             .or(NaiveDateTime::parse_from_str(&s, FORMAT3))
             .or(NaiveDateTime::parse_from_str(&s, FORMAT4))
             .or(NaiveDate::parse_from_str(&s, FORMAT5)
                 .map(|date| date.and_time(NaiveTime::default())))
-            .map(|date| Some(date))
-            .map_err(serde::de::Error::custom);
-        res
+            .map(Some)
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -47,7 +46,7 @@ pub mod np_date_format {
     use chrono::NaiveDateTime;
     use serde::{self, Deserialize, Deserializer, Serializer};
 
-    const FORMAT: &'static str = "%d-%m-%Y %H:%M:%S";
+    const FORMAT: &str = "%d-%m-%Y %H:%M:%S";
 
     pub fn serialize<S>(date: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -70,7 +69,7 @@ pub mod id_date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer, Serializer};
 
-    const FORMAT: &'static str = "%d.%m.%Y";
+    const FORMAT: &str = "%d.%m.%Y";
 
     pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -88,3 +87,49 @@ pub mod id_date_format {
         NaiveDate::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)
     }
 }
+
+pub mod time_format {
+    use chrono::NaiveTime;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    const FORMAT: &str = "%H:%M";
+
+    pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let s = format!("{}", time.format(FORMAT));
+        serializer.serialize_str(&s)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        NaiveTime::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)
+    }
+}
+
+pub mod id_date_format_option {
+    use chrono::NaiveDate;
+    use serde::Serializer;
+
+    use super::id_date_format;
+
+    pub fn serialize<S>(date: &Option<NaiveDate>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => id_date_format::serialize(date, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Current wall clock time in Kyiv. Send dates and warehouse schedules are in
+/// Kyiv time, whatever the timezone of the host.
+pub(crate) fn kyiv_now() -> chrono::NaiveDateTime {
+    chrono::Utc::now().with_timezone(&chrono_tz::Europe::Kyiv).naive_local()
+}
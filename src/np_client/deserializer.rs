@@ -1,17 +1,17 @@
+/// Accepts the API's `"0"`/`"1"` strings as well as plain booleans, so
+/// models serialized by this crate can be read back.
 pub fn deserialize_bool_from_str_num<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s: &str = serde::de::Deserialize::deserialize(deserializer)?;
+    let value: serde_json::Value = serde::de::Deserialize::deserialize(deserializer)?;
 
-    let num = s
-        .parse::<u8>()
-        .map_err(|_| serde::de::Error::unknown_variant(&s, &["0", "1"]))?;
-    match num {
-        0 => Ok(false),
-        1 => Ok(true),
-        _ => Err(serde::de::Error::unknown_variant(
-            &num.to_string(),
+    match value {
+        serde_json::Value::Bool(b) => Ok(b),
+        serde_json::Value::String(s) if s == "0" => Ok(false),
+        serde_json::Value::String(s) if s == "1" => Ok(true),
+        other => Err(serde::de::Error::unknown_variant(
+            &other.to_string(),
             &["0", "1"],
         )),
     }
@@ -27,18 +27,18 @@ where
         return s
             .to_string()
             .parse::<f32>()
-            .map(|val| Some(val))
+            .map(Some)
             .map_err(|_| {
                 serde::de::Error::unknown_variant(&s.to_string(), &["number or empty string"])
             });
     }
     if s.is_string() {
         return match s.as_str().unwrap() {
-            s if s.is_empty() => Ok(None),
+            "" => Ok(None),
             s => s.parse::<f32>()
-                .map(|val| Some(val))
+                .map(Some)
                 .map_err(|_| {
-                    serde::de::Error::unknown_variant(&s.to_string(), &["number or empty string"])
+                    serde::de::Error::unknown_variant(s, &["number or empty string"])
                 })
         }
         // if s.as_str().unwrap().is_empty() {
@@ -73,18 +73,18 @@ where
         return s
             .to_string()
             .parse::<u16>()
-            .map(|val| Some(val))
+            .map(Some)
             .map_err(|_| {
                 serde::de::Error::unknown_variant(&s.to_string(), &["number or empty string"])
             });
     }
     if s.is_string() {
         return match s.as_str().unwrap() {
-            s if s.is_empty() => Ok(None),
+            "" => Ok(None),
             s => s.parse::<u16>()
-                .map(|val| Some(val))
+                .map(Some)
                 .map_err(|_| {
-                    serde::de::Error::unknown_variant(&s.to_string(), &["number or empty string"])
+                    serde::de::Error::unknown_variant(s, &["number or empty string"])
                 })
         }
     }
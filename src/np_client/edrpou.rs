@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum EdrpouParsingError {
+    #[error("Invalid value length")]
+    InvalidLength,
+    #[error("Value contains unexpected symbols")]
+    InvalidSymbols,
+    #[error("Invalid check digit")]
+    InvalidChecksum,
+}
+
+/// EDRPOU code of a Ukrainian legal entity, validated with its check digit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String")]
+pub struct Edrpou(String);
+
+impl Edrpou {
+    /// Expects all eight digits: the weights depend on the whole code, while
+    /// only the first seven are summed.
+    fn check_digit(digits: &[u32]) -> u32 {
+        let code = digits.iter().fold(0, |acc, d| acc * 10 + d);
+        let weights: [u32; 7] = if !(30_000_000..=60_000_000).contains(&code) {
+            [1, 2, 3, 4, 5, 6, 7]
+        } else {
+            [7, 1, 2, 3, 4, 5, 6]
+        };
+
+        let weighted_sum = |shift: u32| -> u32 {
+            digits
+                .iter()
+                .zip(weights.iter())
+                .map(|(d, w)| d * (w + shift))
+                .sum::<u32>()
+                % 11
+        };
+
+        match weighted_sum(0) {
+            10 => match weighted_sum(2) {
+                10 => 0,
+                check => check,
+            },
+            check => check,
+        }
+    }
+}
+
+impl FromStr for Edrpou {
+    type Err = EdrpouParsingError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.len() != 8 {
+            return Err(EdrpouParsingError::InvalidLength);
+        }
+        let digits = value
+            .chars()
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+            .ok_or(EdrpouParsingError::InvalidSymbols)?;
+
+        if Self::check_digit(&digits) != digits[7] {
+            return Err(EdrpouParsingError::InvalidChecksum);
+        }
+        Ok(Self(value.to_owned()))
+    }
+}
+
+impl TryFrom<String> for Edrpou {
+    type Error = EdrpouParsingError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl AsRef<str> for Edrpou {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -1,19 +1,69 @@
+use std::fmt;
 use std::str::FromStr;
+use std::sync::LazyLock;
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
-#[derive(thiserror::Error, Debug)]
-pub enum ENumberPasingError {
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ENumberParsingError {
+    #[error("Value is empty")]
+    Empty,
     #[error("Invalid value length")]
     InvalidLength,
     #[error("Value contains unexpected symbols")]
     InvalidSymbols,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+static EN_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+$").unwrap());
+
+#[deprecated(note = "renamed to `ENumberParsingError`")]
+pub type ENumberPasingError = ENumberParsingError;
+
+/// Format of an express waybill number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ENumberKind {
+    /// 14 digits. Return and redirect waybills are created as regular
+    /// domestic documents and share this format.
+    Domestic,
+    /// 20 digits, used for international and marketplace shipments.
+    International,
+    /// Any other format found in an API response.
+    Other,
+}
+
+/// Express waybill number. Only the format is checked: Nova Poshta doesn't
+/// publish a check digit scheme for it.
+///
+/// Parsing with [`FromStr`] accepts the known formats only. Numbers read
+/// from API responses are taken as they are, so an unexpected format
+/// doesn't fail the whole response; see [`ENumberKind::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "String")]
 pub struct ENumber(String);
 
+impl<'de> Deserialize<'de> for ENumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+impl ENumber {
+    pub fn kind(&self) -> ENumberKind {
+        if !EN_REGEX.is_match(&self.0) {
+            return ENumberKind::Other;
+        }
+        match self.0.len() {
+            14 => ENumberKind::Domestic,
+            20 => ENumberKind::International,
+            _ => ENumberKind::Other,
+        }
+    }
+}
+
 impl From<ENumber> for String {
     fn from(en: ENumber) -> Self {
         en.0
@@ -21,24 +71,93 @@ impl From<ENumber> for String {
 }
 
 impl FromStr for ENumber {
-    type Err = ENumberPasingError;
+    type Err = ENumberParsingError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        if value.len() != 14 {
-            return Err(ENumberPasingError::InvalidLength);
+        if value.is_empty() {
+            return Err(ENumberParsingError::Empty);
         }
-        let en_regex = Regex::new(r"[0-9+]").unwrap();
-
-        if en_regex.is_match(&value) {
-            Ok(Self(value.to_owned()))
-        } else {
-            Err(ENumberPasingError::InvalidSymbols)
+        if !EN_REGEX.is_match(value) {
+            return Err(ENumberParsingError::InvalidSymbols);
+        }
+        match value.len() {
+            14 | 20 => Ok(Self(value.to_owned())),
+            _ => Err(ENumberParsingError::InvalidLength),
         }
     }
 }
 
+impl fmt::Display for ENumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 impl AsRef<str> for ENumber {
     fn as_ref(&self) -> &str {
         &self.0
     }
 }
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClientBarcodeError {
+    #[error("Value is empty")]
+    Empty,
+    #[error("Value is longer than 36 characters")]
+    TooLong,
+    #[error("Value contains control characters or surrounding whitespace")]
+    InvalidSymbols,
+}
+
+/// Sender's own barcode printed on the waybill label
+/// (`InfoRegClientBarcodes` on creation): up to 36 characters.
+///
+/// Only used on the request side. Barcodes set through the web cabinet or
+/// other integrations aren't bound by these checks, so responses keep them as
+/// plain strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ClientBarcode(String);
+
+impl From<ClientBarcode> for String {
+    fn from(barcode: ClientBarcode) -> Self {
+        barcode.0
+    }
+}
+
+impl TryFrom<String> for ClientBarcode {
+    type Error = ClientBarcodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl FromStr for ClientBarcode {
+    type Err = ClientBarcodeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err(ClientBarcodeError::Empty);
+        }
+        if value.chars().count() > 36 {
+            return Err(ClientBarcodeError::TooLong);
+        }
+        if value.trim() != value || value.chars().any(char::is_control) {
+            return Err(ClientBarcodeError::InvalidSymbols);
+        }
+        Ok(Self(value.to_owned()))
+    }
+}
+
+impl fmt::Display for ClientBarcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for ClientBarcode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -1,16 +1,28 @@
+use std::fmt;
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum CounterpartyType {
     PrivatePerson,
     Organization,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl TryFrom<&str> for CounterpartyType {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value {
+            "PrivatePerson" => Ok(Self::PrivatePerson),
+            "Organization" => Ok(Self::Organization),
+            _ => Err("Invalid value".to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum CounterpartyRole {
     Sender,
     Recipient,
@@ -22,7 +34,8 @@ impl TryFrom<&str> for CounterpartyRole {
     fn try_from(value: &str) -> Result<Self, String> {
         match value {
             "Sender" => Ok(Self::Sender),
-            "Recepient" => Ok(Self::Recipient),
+            // The API has used both spellings.
+            "Recipient" | "Recepient" => Ok(Self::Recipient),
             "ThirdPerson" => Ok(Self::ThirdPerson),
             _ => Err("Invalid value".to_owned()),
         }
@@ -41,7 +54,17 @@ pub struct Coordinates {
     longitude: f32,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl Coordinates {
+    pub fn latitude(&self) -> f32 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f32 {
+        self.longitude
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ServiceType {
     DoorsDoors,
     DoorsWarehouse,
@@ -49,14 +72,39 @@ pub enum ServiceType {
     WarehouseDoors,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl TryFrom<&str> for ServiceType {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value {
+            "DoorsDoors" => Ok(Self::DoorsDoors),
+            "DoorsWarehouse" => Ok(Self::DoorsWarehouse),
+            "WarehouseWarehouse" => Ok(Self::WarehouseWarehouse),
+            "WarehouseDoors" => Ok(Self::WarehouseDoors),
+            _ => Err("Invalid value".to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum PaymentMethod {
     Cash,
     Card,
     NonCash,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl TryFrom<&str> for PaymentMethod {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value {
+            "Cash" => Ok(Self::Cash),
+            "Card" => Ok(Self::Card),
+            "NonCash" => Ok(Self::NonCash),
+            _ => Err("Invalid value".to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum CargoType {
     Parcel,
     Cargo,
@@ -65,46 +113,140 @@ pub enum CargoType {
     Pallet,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CityID (Uuid);
+impl TryFrom<&str> for CargoType {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value {
+            "Parcel" => Ok(Self::Parcel),
+            "Cargo" => Ok(Self::Cargo),
+            "Documents" => Ok(Self::Documents),
+            "TiresWheels" => Ok(Self::TiresWheels),
+            "Pallet" => Ok(Self::Pallet),
+            _ => Err("Invalid value".to_owned()),
+        }
+    }
+}
 
-impl FromStr for CityID {
-    type Err = uuid::Error;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BackwardDeliveryCargoType {
+    Documents,
+    Money,
+    CreditDocuments,
+    Trays,
+    SignedDocuments,
+    Other,
+}
 
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Ok(CityID(Uuid::parse_str(value)?))
+impl TryFrom<&str> for BackwardDeliveryCargoType {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, String> {
+        match value {
+            "Documents" => Ok(Self::Documents),
+            "Money" => Ok(Self::Money),
+            "CreditDocuments" => Ok(Self::CreditDocuments),
+            "Trays" => Ok(Self::Trays),
+            "SignedDocuments" => Ok(Self::SignedDocuments),
+            "Other" => Ok(Self::Other),
+            _ => Err("Invalid value".to_owned()),
+        }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CounterpartyID (Uuid);
+macro_rules! ref_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub struct $name (Uuid);
 
-impl FromStr for CounterpartyID {
-    type Err = uuid::Error;
+        impl $name {
+            pub fn as_uuid(&self) -> Uuid {
+                self.0
+            }
+        }
 
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Ok(CounterpartyID(Uuid::parse_str(value)?))
-    }
-}
+        impl FromStr for $name {
+            type Err = uuid::Error;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok($name(Uuid::parse_str(value)?))
+            }
+        }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ContactPersonID (Uuid);
+        impl From<Uuid> for $name {
+            fn from(value: Uuid) -> Self {
+                $name(value)
+            }
+        }
 
-impl FromStr for ContactPersonID {
-    type Err = uuid::Error;
+        impl From<$name> for Uuid {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
 
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Ok(ContactPersonID(Uuid::parse_str(value)?))
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+ref_id!(CityID);
+ref_id!(CounterpartyID);
+ref_id!(ContactPersonID);
+ref_id!(AddressID);
+ref_id!(
+    /// Ref of a city street from `getStreet`, the one `Address.save` takes.
+    StreetRef
+);
+ref_id!(
+    /// Ref of a settlement street from `searchSettlementStreets`. Not
+    /// interchangeable with [`StreetRef`].
+    SettlementStreetID
+);
+ref_id!(WarehouseID);
+ref_id!(SettlementID);
+ref_id!(
+    /// Ref of an oblast.
+    AreaID
+);
+ref_id!(
+    /// Ref of a district (raion) inside an oblast.
+    RegionID
+);
+ref_id!(
+    /// Ref of an express waybill, as opposed to its printed `ENumber`.
+    DocumentID
+);
+ref_id!(OwnershipFormID);
+ref_id!(PackID);
+
+// A warehouse is one of the API's address objects, and `RecipientAddress`
+// and `SenderAddress` take its ref as is for warehouse delivery.
+impl From<WarehouseID> for AddressID {
+    fn from(warehouse_ref: WarehouseID) -> Self {
+        AddressID(warehouse_ref.0)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AddressID (Uuid);
+/// Code of a courier delivery slot, e.g. `CityDeliveryTimeInterval2`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeIntervalCode (String);
 
-impl FromStr for AddressID {
-    type Err = uuid::Error;
+impl FromStr for TimeIntervalCode {
+    type Err = String;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Ok(AddressID(Uuid::parse_str(value)?))
+        if value.is_empty() {
+            return Err("Empty time interval code".to_owned());
+        }
+        Ok(TimeIntervalCode(value.to_owned()))
     }
-}
\ No newline at end of file
+}
+
+impl AsRef<str> for TimeIntervalCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -1,20 +1,29 @@
-use chrono::NaiveDate;
+use chrono::{Duration, NaiveDate};
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DisplayFromStr};
-use uuid::Uuid;
-use phonenumber::{self, PhoneNumber};
+use serde_with::{serde_as, BoolFromInt, DisplayFromStr};
 
-use super::date_format::id_date_format;
+use super::address::warehouses::{ParcelLimits, ParcelRejection, ParcelSpec, Warehouse};
+use super::date_format::{id_date_format, id_date_format_option, kyiv_now};
 use super::{NPClient, NPRequest};
-use super::en::ENumber;
-use super::helper_structs::{CounterpartyRole, PaymentMethod, ServiceType, CargoType, CounterpartyID, CityID, AddressID, ContactPersonID};
+use super::en::{ClientBarcode, ENumber};
+use super::phone::UaPhone;
+use super::helper_structs::{BackwardDeliveryCargoType, CounterpartyRole, CounterpartyType, WarehouseID, PaymentMethod, ServiceType, CargoType, CounterpartyID, CityID, AddressID, ContactPersonID, DocumentID, PackID, SettlementID, SettlementStreetID, TimeIntervalCode};
 use super::res_template::ResponseTemplate;
 
+use self::batch::CreateManyBuilder;
+use self::document_list::{GetDocumentListBuilder, ListedDocument};
+
+pub mod batch;
+pub mod document_list;
+
+/// Rounding slack, in kg, when comparing seat weights with the total weight.
+const SEATS_WEIGHT_TOLERANCE: f32 = 0.01;
+
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct IDocument {
-    r#ref: Uuid,
+    r#ref: DocumentID,
     cost_on_site: u16,
     #[serde(with = "id_date_format")]
     estimated_delivery_date: NaiveDate,
@@ -22,6 +31,41 @@ pub struct IDocument {
     type_document: String,
 }
 
+impl IDocument {
+    pub fn document_id(&self) -> DocumentID {
+        self.r#ref
+    }
+
+    pub fn int_doc_number(&self) -> &ENumber {
+        &self.int_doc_number
+    }
+}
+
+/// Result of [`CreateIDocumentBuilder::send_idempotent`].
+#[derive(Debug)]
+pub enum SendOutcome {
+    Created(IDocument),
+    /// A waybill with the same client barcode was created by an earlier,
+    /// timed out attempt.
+    AlreadyExists(ListedDocument),
+}
+
+impl SendOutcome {
+    pub fn document_id(&self) -> DocumentID {
+        match self {
+            Self::Created(document) => document.document_id(),
+            Self::AlreadyExists(document) => document.document_id(),
+        }
+    }
+
+    pub fn int_doc_number(&self) -> &ENumber {
+        match self {
+            Self::Created(document) => document.int_doc_number(),
+            Self::AlreadyExists(document) => document.int_doc_number(),
+        }
+    }
+}
+
 pub struct IDocumentHandler<'c> {
     client: &'c NPClient,
 }
@@ -36,6 +80,30 @@ impl<'cli> IDocumentHandler<'cli> {
     pub fn create_document(&self) -> CreateIDocumentBuilder<'cli, NoGeneralInfo, NoSenderInfo, NoRecipientInfo> {
         CreateIDocumentBuilder::new(self.client)
     }
+
+    pub fn get_document_list(&self) -> GetDocumentListBuilder<'cli> {
+        GetDocumentListBuilder::new(self.client)
+    }
+
+    /// Looks up a waybill dated between `from` and `to` by its client
+    /// barcode.
+    pub async fn find_by_client_barcode(&self, barcode: &ClientBarcode, from: NaiveDate, to: NaiveDate) -> Result<Option<ListedDocument>, CreateIDocumentError> {
+        let res = self.get_document_list()
+            .date_time_from(from)
+            .date_time_to(to)
+            .send()
+            .await?;
+        if !res.success {
+            return Err(CreateIDocumentError::LookupRejected(res.errors));
+        }
+        Ok(res.data.into_iter().find(|document| document.client_barcode() == Some(barcode.as_ref())))
+    }
+
+    /// Creates a batch of waybills, each paired with a caller's correlation
+    /// key, see [`CreateManyBuilder`].
+    pub fn create_many<K, R>(&self, items: Vec<(K, CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, R>)>) -> CreateManyBuilder<'cli, K, R> {
+        CreateManyBuilder::new(items)
+    }
 }
 
 #[serde_as]
@@ -45,9 +113,119 @@ pub struct RecepientInfo {
     city_recipient: CityID, 
     recipient: CounterpartyID, 
     recipient_address: AddressID, 
-    #[serde_as(as = "DisplayFromStr")]
-    recipients_phone: PhoneNumber,
+    recipients_phone: UaPhone,
     contact_recipient: ContactPersonID,
+
+    #[serde(skip)]
+    recipient_type: Option<CounterpartyType>,
+}
+
+/// Recipient passed inline instead of a pre-created counterparty, contact
+/// person and address. Delivery goes to the given warehouse or postomat.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SimpleRecipientInfo {
+    new_address: &'static str,
+    recipient_name: String,
+    recipient_type: CounterpartyType,
+    recipients_phone: UaPhone,
+    city_recipient: CityID,
+    recipient_address: WarehouseID,
+
+    #[serde(skip)]
+    limits: ParcelLimits,
+}
+
+/// Private person recipient passed inline with a door delivery address. The
+/// settlement and street refs come from `searchSettlements` and
+/// `searchSettlementStreets`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SimpleAddressRecipientInfo {
+    new_address: &'static str,
+    recipient_name: String,
+    recipient_type: CounterpartyType,
+    recipients_phone: UaPhone,
+    recipient_city_name: SettlementID,
+    recipient_address_name: SettlementStreetID,
+    recipient_house: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient_flat: Option<String>,
+}
+
+/// Parcel doesn't satisfy the restrictions of the recipient warehouse, which
+/// matters mostly for postomats.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq)]
+pub enum RecipientWarehouseError {
+    #[error("Parcel weight exceeds the warehouse limit of {0} kg")]
+    TooHeavy(u16),
+    #[error("Seat {0} exceeds the warehouse dimension limits")]
+    SeatTooLarge(usize),
+    #[error("Seat {0} exceeds the warehouse weight limit")]
+    SeatTooHeavy(usize),
+    #[error("Declared cost exceeds the warehouse limit of {0}")]
+    DeclaredCostTooHigh(f32),
+}
+
+impl From<ParcelRejection> for RecipientWarehouseError {
+    fn from(rejection: ParcelRejection) -> Self {
+        match rejection {
+            ParcelRejection::TooHeavy { limit } => Self::TooHeavy(limit),
+            ParcelRejection::SeatTooLarge { seat, .. } => Self::SeatTooLarge(seat),
+            ParcelRejection::SeatTooHeavy { seat, .. } => Self::SeatTooHeavy(seat),
+            ParcelRejection::DeclaredCostTooHigh { limit } => Self::DeclaredCostTooHigh(limit),
+        }
+    }
+}
+
+/// Value-level problem with a waybill found before sending it.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq)]
+pub enum IDocumentViolation {
+    #[error("Weight must be positive")]
+    NonPositiveWeight,
+    #[error("Seats amount must be positive")]
+    NoSeats,
+    #[error("Send date is in the past")]
+    DateInPast,
+    #[error("Description is empty")]
+    EmptyDescription,
+    #[error("Documents can't weigh more than 1 kg")]
+    DocumentsTooHeavy,
+    /// Only checked when the recipient pays: inline recipients are always
+    /// private persons, and a pre-created one is checked once its type is
+    /// given with `recipient_type`. The waybill doesn't carry the type of the
+    /// sender or a third person, so those payers are left for the API to
+    /// reject.
+    #[error("Non-cash payment is not available for a private person payer")]
+    NonCashForPrivatePerson,
+    #[error("Preferred delivery date is before the send date")]
+    PreferredDateBeforeSend,
+    #[error("Afterpayment on goods cost can't be combined with a money backward delivery")]
+    AfterpaymentWithMoneyBack,
+    #[error("Delivery time interval needs a door delivery service type")]
+    TimeIntervalWithoutDoorDelivery,
+    #[error("Number of seats in OptionsSeat differs from the seats amount")]
+    SeatsAmountMismatch,
+    #[error("Seat weights don't add up to the waybill weight")]
+    SeatsWeightMismatch,
+    #[error(transparent)]
+    RecipientWarehouse(#[from] RecipientWarehouseError),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CreateIDocumentError {
+    #[error("Waybill failed validation: {0:?}")]
+    Invalid(Vec<IDocumentViolation>),
+    #[error("Waybill rejected by the API: {0:?}")]
+    Rejected(Vec<String>),
+    #[error("Idempotent send requires a client barcode")]
+    NoClientBarcode,
+    /// The client barcode lookup failed, so it's unknown whether the waybill
+    /// already exists.
+    #[error("Waybill lookup rejected by the API: {0:?}")]
+    LookupRejected(Vec<String>),
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
 }
 
 #[serde_as]
@@ -58,8 +236,7 @@ pub struct SenderInfo {
     sender: CounterpartyID,
     sender_address: AddressID, 
     contact_sender: ContactPersonID,
-    #[serde_as(as = "DisplayFromStr")]
-    senders_phone: PhoneNumber,
+    senders_phone: UaPhone,
 }
 
 #[serde_as]
@@ -81,6 +258,172 @@ pub struct IDGeneral {
     cost: u16,
 }
 
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatError {
+    #[error("Seat exceeds warehouse dimension limits")]
+    TooLarge,
+    #[error("Seat exceeds warehouse weight limit")]
+    TooHeavy,
+}
+
+/// Single seat of a multi-seat parcel. Sizes are in cm, weight in kg.
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Seat {
+    #[serde_as(as = "DisplayFromStr")]
+    volumetric_volume: f32,
+    #[serde_as(as = "DisplayFromStr")]
+    volumetric_width: f32,
+    #[serde_as(as = "DisplayFromStr")]
+    volumetric_length: f32,
+    #[serde_as(as = "DisplayFromStr")]
+    volumetric_height: f32,
+    #[serde_as(as = "DisplayFromStr")]
+    weight: f32,
+    #[serde_as(as = "BoolFromInt")]
+    special_cargo: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pack_ref: Option<PackID>,
+}
+
+impl Seat {
+    pub fn new(width: f32, length: f32, height: f32, weight: f32) -> Self {
+        Self {
+            volumetric_volume: width * length * height / 1_000_000.0,
+            volumetric_width: width,
+            volumetric_length: length,
+            volumetric_height: height,
+            weight,
+            special_cargo: false,
+            pack_ref: None,
+        }
+    }
+
+    pub fn special_cargo(mut self, special_cargo: bool) -> Self {
+        self.special_cargo = special_cargo;
+        self
+    }
+
+    /// Packaging the branch should use for this seat, see
+    /// `CommonHandler::get_packs`.
+    pub fn pack_ref(mut self, pack_ref: PackID) -> Self {
+        self.pack_ref = Some(pack_ref);
+        self
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Checks the seat against the limits the sending warehouse accepts,
+    /// returning every limit it breaks.
+    pub fn check_sending_limits(&self, warehouse: &Warehouse) -> Result<(), Vec<SeatError>> {
+        let mut errors = Vec::new();
+        let fits = warehouse.sending_limitations_on_dimensions().fits(
+            self.volumetric_width,
+            self.volumetric_length,
+            self.volumetric_height,
+        );
+        if !fits {
+            errors.push(SeatError::TooLarge);
+        }
+        if warehouse.place_max_weight_allowed().is_some_and(|max| self.weight > max as f32) {
+            errors.push(SeatError::TooHeavy);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Cargo returned to the sender once the parcel is delivered, e.g. cash on
+/// delivery.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BackwardDelivery {
+    payer_type: CounterpartyRole,
+    cargo_type: BackwardDeliveryCargoType,
+    redelivery_string: String,
+}
+
+impl BackwardDelivery {
+    pub fn new(payer_type: CounterpartyRole, cargo_type: BackwardDeliveryCargoType, redelivery_string: String) -> Self {
+        Self {
+            payer_type,
+            cargo_type,
+            redelivery_string,
+        }
+    }
+
+    /// Cash on delivery: the recipient pays `amount` UAH which is sent back.
+    pub fn money(payer_type: CounterpartyRole, amount: f32) -> Self {
+        Self::new(payer_type, BackwardDeliveryCargoType::Money, amount.to_string())
+    }
+
+    pub fn documents(payer_type: CounterpartyRole, description: String) -> Self {
+        Self::new(payer_type, BackwardDeliveryCargoType::Documents, description)
+    }
+
+    pub fn cargo_type(&self) -> BackwardDeliveryCargoType {
+        self.cargo_type
+    }
+}
+
+/// Optional waybill fields, settable at any stage of the builder.
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct IDOptions {
+    /// Courier delivery slot, see `CommonHandler::get_time_intervals`.
+    /// Only applies to `WarehouseDoors` and `DoorsDoors` deliveries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_interval: Option<TimeIntervalCode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options_seat: Option<Vec<Seat>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    backward_delivery_data: Vec<BackwardDelivery>,
+
+    /// Payment control: the recipient pays the goods cost to the sender's
+    /// account instead of a money backward delivery.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    afterpayment_on_goods_cost: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    additional_information: Option<String>,
+
+    /// Printed on the label and returned as `ClientBarcode` when tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    info_reg_client_barcodes: Option<ClientBarcode>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_of_floors_lifting: Option<u8>,
+
+    #[serde_as(as = "Option<BoolFromInt>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elevator: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    saturday_delivery: Option<bool>,
+
+    #[serde(with = "id_date_format_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preferred_delivery_date: Option<NaiveDate>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accompanying_documents: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    red_box_barcode: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, RecepientInfo> {
@@ -95,6 +438,9 @@ pub struct CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, RecepientInfo> {
 
     #[serde(flatten)]
     sender_info: SenderInfo,
+
+    #[serde(flatten)]
+    options: IDOptions,
 }
 
 #[derive(Debug, Serialize)]
@@ -113,6 +459,7 @@ impl<'cli> CreateIDocumentBuilder<'cli, NoGeneralInfo, NoSenderInfo, NoRecipient
             general_info: NoGeneralInfo{},
             sender_info: NoSenderInfo {},
             recipient_info: NoRecipientInfo{},
+            options: IDOptions::default(),
         }
     }
 }
@@ -121,6 +468,7 @@ impl<'cli, S, R> CreateIDocumentBuilder<'cli, NoGeneralInfo, S, R>
     where S: Serialize,
         R: Serialize,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn general_info(
         self, 
         payer_type: CounterpartyRole,
@@ -148,6 +496,7 @@ impl<'cli, S, R> CreateIDocumentBuilder<'cli, NoGeneralInfo, S, R>
             },
             sender_info: self.sender_info,
             recipient_info: self.recipient_info,
+            options: self.options,
         }
     } 
 }
@@ -162,7 +511,7 @@ impl<'cli, G, R> CreateIDocumentBuilder<'cli, G, NoSenderInfo, R>
         sender: CounterpartyID,
         sender_address: AddressID, 
         contact_sender: ContactPersonID,
-        senders_phone: PhoneNumber,
+        senders_phone: UaPhone,
     ) -> CreateIDocumentBuilder<'cli, G, SenderInfo, R> {
         CreateIDocumentBuilder {
             client: self.client,
@@ -175,6 +524,7 @@ impl<'cli, G, R> CreateIDocumentBuilder<'cli, G, NoSenderInfo, R>
                 contact_sender,
             },
             recipient_info: self.recipient_info,
+            options: self.options,
         }
     } 
 }
@@ -189,7 +539,7 @@ impl<'cli, G, S> CreateIDocumentBuilder<'cli, G, S, NoRecipientInfo>
         recipient: CounterpartyID, 
         recipient_address: AddressID, 
         contact_recipient: ContactPersonID,
-        recipients_phone: PhoneNumber,
+        recipients_phone: UaPhone,
     ) -> CreateIDocumentBuilder<'cli, G, S, RecepientInfo> {
         CreateIDocumentBuilder {
             client: self.client,
@@ -201,29 +551,374 @@ impl<'cli, G, S> CreateIDocumentBuilder<'cli, G, S, NoRecipientInfo>
                 recipient_address, 
                 recipients_phone, 
                 contact_recipient, 
+                recipient_type: None,
             },
+            options: self.options,
         }
     } 
 }
 
-impl<'cli> CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, RecepientInfo> {
-    pub async fn send(self) -> Result<ResponseTemplate<IDocument>, reqwest::Error> {
+impl<'cli, G, S> CreateIDocumentBuilder<'cli, G, S, RecepientInfo> {
+    /// Type of the pre-created recipient, lets `validate` check the payment
+    /// method against it.
+    pub fn recipient_type(mut self, recipient_type: CounterpartyType) -> Self {
+        self.recipient_info.recipient_type = Some(recipient_type);
+        self
+    }
+}
+
+impl<'cli, G, S> CreateIDocumentBuilder<'cli, G, S, NoRecipientInfo> 
+    where G: Serialize,
+        S: Serialize,
+{
+    /// Private person recipient created by the API on the fly
+    /// (`NewAddress=1`). Weight, seats and declared cost are checked against
+    /// the warehouse limits on `send`.
+    pub fn simple_recipient_info(
+        self,
+        recipient_name: String,
+        recipients_phone: UaPhone,
+        warehouse: &Warehouse,
+    ) -> CreateIDocumentBuilder<'cli, G, S, SimpleRecipientInfo> {
+        CreateIDocumentBuilder {
+            client: self.client,
+            general_info: self.general_info,
+            sender_info: self.sender_info,
+            recipient_info: SimpleRecipientInfo {
+                new_address: "1",
+                recipient_name,
+                recipient_type: CounterpartyType::PrivatePerson,
+                recipients_phone,
+                city_recipient: warehouse.city_ref(),
+                recipient_address: warehouse.r#ref(),
+                limits: warehouse.receiving_limits(),
+            },
+            options: self.options,
+        }
+    }
+
+    /// Private person recipient created by the API on the fly
+    /// (`NewAddress=1`), delivered to the door. Use with a `*Doors` service
+    /// type.
+    #[allow(clippy::too_many_arguments)]
+    pub fn simple_address_recipient_info(
+        self,
+        recipient_name: String,
+        recipients_phone: UaPhone,
+        settlement_ref: SettlementID,
+        street_ref: SettlementStreetID,
+        house: String,
+        flat: Option<String>,
+    ) -> CreateIDocumentBuilder<'cli, G, S, SimpleAddressRecipientInfo> {
+        CreateIDocumentBuilder {
+            client: self.client,
+            general_info: self.general_info,
+            sender_info: self.sender_info,
+            recipient_info: SimpleAddressRecipientInfo {
+                new_address: "1",
+                recipient_name,
+                recipient_type: CounterpartyType::PrivatePerson,
+                recipients_phone,
+                recipient_city_name: settlement_ref,
+                recipient_address_name: street_ref,
+                recipient_house: house,
+                recipient_flat: flat,
+            },
+            options: self.options,
+        }
+    }
+}
+
+impl<'cli, G, S, R> CreateIDocumentBuilder<'cli, G, S, R> {
+    pub fn time_interval(mut self, time_interval: TimeIntervalCode) -> Self {
+        self.options.time_interval = Some(time_interval);
+        self
+    }
+
+    pub fn backward_delivery(mut self, backward_delivery: BackwardDelivery) -> Self {
+        self.options.backward_delivery_data.push(backward_delivery);
+        self
+    }
+
+    pub fn afterpayment_on_goods_cost(mut self, cost: f32) -> Self {
+        self.options.afterpayment_on_goods_cost = Some(cost);
+        self
+    }
+
+    pub fn additional_information(mut self, information: String) -> Self {
+        self.options.additional_information = Some(information);
+        self
+    }
+
+    pub fn client_barcode(mut self, barcode: ClientBarcode) -> Self {
+        self.options.info_reg_client_barcodes = Some(barcode);
+        self
+    }
+
+    /// Lifting the parcel to `floors` floor on delivery, by elevator or by
+    /// stairs.
+    pub fn floors_lifting(mut self, floors: u8, elevator: bool) -> Self {
+        self.options.number_of_floors_lifting = Some(floors);
+        self.options.elevator = Some(elevator);
+        self
+    }
+
+    pub fn saturday_delivery(mut self, saturday_delivery: bool) -> Self {
+        self.options.saturday_delivery = Some(saturday_delivery);
+        self
+    }
+
+    pub fn preferred_delivery_date(mut self, date: NaiveDate) -> Self {
+        self.options.preferred_delivery_date = Some(date);
+        self
+    }
+
+    pub fn accompanying_documents(mut self, documents: String) -> Self {
+        self.options.accompanying_documents = Some(documents);
+        self
+    }
+
+    pub fn red_box_barcode(mut self, barcode: String) -> Self {
+        self.options.red_box_barcode = Some(barcode);
+        self
+    }
+
+    pub fn options_seat(mut self, seats: Vec<Seat>) -> Self {
+        self.options.options_seat = Some(seats);
+        self
+    }
+
+    /// Same as [`Self::options_seat`], rejecting seats the sending warehouse
+    /// can't accept. Returns every error of every offending seat along with
+    /// the seat index.
+    pub fn options_seat_for_warehouse(self, seats: Vec<Seat>, warehouse: &Warehouse) -> Result<Self, Vec<(usize, SeatError)>> {
+        let errors = seats
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, seat)| {
+                seat.check_sending_limits(warehouse)
+                    .err()
+                    .into_iter()
+                    .flatten()
+                    .map(move |err| (idx, err))
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(self.options_seat(seats))
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::RecepientInfo {}
+    impl Sealed for super::SimpleRecipientInfo {}
+    impl Sealed for super::SimpleAddressRecipientInfo {}
+}
+
+/// Recipient section of a complete waybill: [`RecepientInfo`],
+/// [`SimpleRecipientInfo`] or [`SimpleAddressRecipientInfo`].
+pub trait RecipientSection: Serialize + sealed::Sealed {
+    #[doc(hidden)]
+    fn violations(&self, general: &IDGeneral, options: &IDOptions) -> Vec<IDocumentViolation>;
+}
+
+impl RecipientSection for RecepientInfo {
+    fn violations(&self, general: &IDGeneral, _options: &IDOptions) -> Vec<IDocumentViolation> {
+        if self.recipient_type == Some(CounterpartyType::PrivatePerson) && non_cash_by_recipient(general) {
+            vec![IDocumentViolation::NonCashForPrivatePerson]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl RecipientSection for SimpleRecipientInfo {
+    fn violations(&self, general: &IDGeneral, options: &IDOptions) -> Vec<IDocumentViolation> {
+        let mut violations = Vec::new();
+        // Inline recipients are always private persons.
+        if non_cash_by_recipient(general) {
+            violations.push(IDocumentViolation::NonCashForPrivatePerson);
+        }
+
+        let parcel = ParcelSpec::new()
+            .total_weight(general.weight)
+            .declared_cost(general.cost as f32);
+        let parcel = match &options.options_seat {
+            Some(seats) => seats.iter().fold(parcel, |parcel, seat| {
+                parcel.seat(seat.volumetric_width, seat.volumetric_length, seat.volumetric_height, seat.weight)
+            }),
+            // Without seat details only an even split of the weight is
+            // known, checked once as seat 0.
+            None => parcel.seat_by_weight(general.weight / general.seats_amount.max(1) as f32),
+        };
+        if let Err(rejections) = self.limits.check(&parcel) {
+            violations.extend(rejections.into_iter().map(|rejection| IDocumentViolation::RecipientWarehouse(rejection.into())));
+        }
+        violations
+    }
+}
+
+fn non_cash_by_recipient(general: &IDGeneral) -> bool {
+    general.payer_type == CounterpartyRole::Recipient && general.payment_method == PaymentMethod::NonCash
+}
+
+impl RecipientSection for SimpleAddressRecipientInfo {
+    fn violations(&self, general: &IDGeneral, _options: &IDOptions) -> Vec<IDocumentViolation> {
+        // Inline recipients are always private persons.
+        if non_cash_by_recipient(general) {
+            vec![IDocumentViolation::NonCashForPrivatePerson]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl<'cli, R> CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, R>
+    where R: RecipientSection,
+{
+    /// Checks field values locally, returning every violation found. For
+    /// inline recipients this includes the recipient warehouse limits.
+    pub fn validate(&self) -> Result<(), Vec<IDocumentViolation>> {
+        let mut violations = self.general_violations();
+        violations.extend(self.recipient_info.violations(&self.general_info, &self.options));
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Runs [`Self::validate`] and sends the waybill if it passes.
+    pub async fn send(self) -> Result<ResponseTemplate<IDocument>, CreateIDocumentError> {
+        self.validate().map_err(CreateIDocumentError::Invalid)?;
+        Ok(self.post().await?)
+    }
+
+    /// Sends the waybill, guarding against duplicates when a request fails
+    /// without a definite answer from the API (timeout, dropped connection,
+    /// server error status, unreadable response): before every re-send the waybills around the
+    /// send date are searched for the waybill's client barcode, and a match
+    /// is returned instead of creating another shipment. Requires
+    /// [`Self::client_barcode`]; makes at most `max_attempts` save requests.
+    pub async fn send_idempotent(self, max_attempts: u8) -> Result<SendOutcome, CreateIDocumentError> {
+        self.send_deduplicated(max_attempts, false).await
+    }
+
+    /// Same as [`Self::send_idempotent`], for a waybill an earlier call may
+    /// already have created, e.g. one interrupted by a restart. The barcode
+    /// is looked up before the first save request as well.
+    pub async fn retry_idempotent(self, max_attempts: u8) -> Result<SendOutcome, CreateIDocumentError> {
+        self.send_deduplicated(max_attempts, true).await
+    }
+
+    async fn send_deduplicated(self, max_attempts: u8, retry: bool) -> Result<SendOutcome, CreateIDocumentError> {
+        self.validate().map_err(CreateIDocumentError::Invalid)?;
+        let barcode = self.options.info_reg_client_barcodes.as_ref()
+            .ok_or(CreateIDocumentError::NoClientBarcode)?;
+        let handler = IDocumentHandler::new(self.client);
+
+        // The list is filtered by waybill date, which may be set ahead of
+        // the day of creation; a day of margin covers attempts around
+        // midnight.
+        let today = kyiv_now().date();
+        let from = self.general_info.date_time.min(today) - Duration::days(1);
+        let to = self.general_info.date_time.max(today) + Duration::days(1);
+
+        let mut last_error = None;
+        for attempt in 1..=max_attempts.max(1) {
+            if attempt > 1 || retry {
+                if let Some(document) = handler.find_by_client_barcode(barcode, from, to).await? {
+                    return Ok(SendOutcome::AlreadyExists(document));
+                }
+            }
+
+            match self.post().await {
+                Ok(res) => {
+                    return match res.data.into_iter().next() {
+                        Some(document) if res.success => Ok(SendOutcome::Created(document)),
+                        _ => Err(CreateIDocumentError::Rejected(res.errors)),
+                    };
+                },
+                // A client error status means the request was turned away.
+                // Anything else, a gateway timeout included, may have reached
+                // the API and created the waybill.
+                Err(err) if err.status().is_some_and(|status| status.is_client_error()) => return Err(err.into()),
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        if let Some(document) = handler.find_by_client_barcode(barcode, from, to).await? {
+            return Ok(SendOutcome::AlreadyExists(document));
+        }
+        Err(last_error.expect("at least one attempt is made").into())
+    }
+
+    async fn post(&self) -> Result<ResponseTemplate<IDocument>, reqwest::Error> {
         let request = NPRequest {
             api_key: &self.client.api_key,
             model_name: "InternetDocument",
             called_method: "save",
-            method_properties: &self
+            method_properties: self
         };
 
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
             .send()
             .await?
+            .error_for_status()?
             .json()
             .await
     }
-}
 
+    fn general_violations(&self) -> Vec<IDocumentViolation> {
+        let general = &self.general_info;
+        let mut violations = Vec::new();
 
+        if general.weight.is_nan() || general.weight <= 0.0 {
+            violations.push(IDocumentViolation::NonPositiveWeight);
+        }
+        if general.seats_amount == 0 {
+            violations.push(IDocumentViolation::NoSeats);
+        }
+        if general.date_time < kyiv_now().date() {
+            violations.push(IDocumentViolation::DateInPast);
+        }
+        if general.description.trim().is_empty() {
+            violations.push(IDocumentViolation::EmptyDescription);
+        }
+        if general.cargo_type == CargoType::Documents && general.weight > 1.0 {
+            violations.push(IDocumentViolation::DocumentsTooHeavy);
+        }
+        if self.options.preferred_delivery_date.is_some_and(|date| date < general.date_time) {
+            violations.push(IDocumentViolation::PreferredDateBeforeSend);
+        }
+        let money_back = self.options.backward_delivery_data
+            .iter()
+            .any(|delivery| delivery.cargo_type() == BackwardDeliveryCargoType::Money);
+        if money_back && self.options.afterpayment_on_goods_cost.is_some() {
+            violations.push(IDocumentViolation::AfterpaymentWithMoneyBack);
+        }
+        let door_delivery = matches!(general.service_type, ServiceType::WarehouseDoors | ServiceType::DoorsDoors);
+        if self.options.time_interval.is_some() && !door_delivery {
+            violations.push(IDocumentViolation::TimeIntervalWithoutDoorDelivery);
+        }
+        if let Some(seats) = &self.options.options_seat {
+            if seats.len() != general.seats_amount as usize {
+                violations.push(IDocumentViolation::SeatsAmountMismatch);
+            }
+            let seats_weight = seats.iter().map(Seat::weight).sum::<f32>();
+            if (seats_weight - general.weight).abs() > SEATS_WEIGHT_TOLERANCE {
+                violations.push(IDocumentViolation::SeatsWeightMismatch);
+            }
+        }
+        violations
+    }
+}
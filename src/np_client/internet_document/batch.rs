@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::fs;
+use tokio::sync::Mutex;
+use tokio::time::{self, Instant};
+
+use super::{CreateIDocumentBuilder, CreateIDocumentError, IDGeneral, IDocument, RecipientSection, SenderInfo};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Outcome of a single waybill of a batch.
+#[derive(Debug)]
+pub struct BatchItem<K> {
+    key: K,
+    result: Result<IDocument, CreateIDocumentError>,
+    from_checkpoint: bool,
+    checkpoint_error: Option<io::Error>,
+}
+
+impl<K> BatchItem<K> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn result(&self) -> &Result<IDocument, CreateIDocumentError> {
+        &self.result
+    }
+
+    /// The waybill was created by an earlier run and taken from the
+    /// checkpoint instead of being sent again.
+    pub fn from_checkpoint(&self) -> bool {
+        self.from_checkpoint
+    }
+
+    /// The waybill was created but recording it to the checkpoint failed.
+    /// It is still recorded by the next successful write of the same run;
+    /// if none follows, a restart would send it again.
+    pub fn checkpoint_error(&self) -> Option<&io::Error> {
+        self.checkpoint_error.as_ref()
+    }
+
+    pub fn into_parts(self) -> (K, Result<IDocument, CreateIDocumentError>) {
+        (self.key, self.result)
+    }
+}
+
+/// Sends a batch of waybills with bounded concurrency and a minimal interval
+/// between requests.
+///
+/// With [`Self::checkpoint`] every created waybill is recorded to a file as
+/// soon as the API confirms it, and keys found there are skipped on the next
+/// run, so a batch interrupted halfway can be restarted without duplicates.
+pub struct CreateManyBuilder<'cli, K, R> {
+    items: Vec<(K, CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, R>)>,
+    concurrency: usize,
+    min_interval: Duration,
+    checkpoint: Option<PathBuf>,
+}
+
+impl<'cli, K, R> CreateManyBuilder<'cli, K, R> {
+    pub(super) fn new(items: Vec<(K, CreateIDocumentBuilder<'cli, IDGeneral, SenderInfo, R>)>) -> Self {
+        Self {
+            items,
+            concurrency: DEFAULT_CONCURRENCY,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            checkpoint: None,
+        }
+    }
+
+    /// Maximum number of requests in flight, 4 by default.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Minimal delay between two request starts, 100ms by default.
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn checkpoint(mut self, path: impl AsRef<Path>) -> Self {
+        self.checkpoint = Some(path.as_ref().to_owned());
+        self
+    }
+}
+
+impl<'cli, K, R> CreateManyBuilder<'cli, K, R>
+    where K: Clone + Eq + Hash + Serialize + DeserializeOwned,
+        R: RecipientSection,
+{
+    /// Returns results in the order of the input items. Fails only if an
+    /// existing checkpoint can't be read, before anything is sent. Failures
+    /// to write the checkpoint are reported per item, see
+    /// [`BatchItem::checkpoint_error`].
+    pub async fn send(self) -> io::Result<Vec<BatchItem<K>>> {
+        let mut created = match &self.checkpoint {
+            Some(path) => load_checkpoint::<K>(path).await?,
+            None => HashMap::new(),
+        };
+
+        let mut results = Vec::with_capacity(self.items.len());
+        let mut pending = Vec::new();
+        for (idx, (key, builder)) in self.items.into_iter().enumerate() {
+            match created.get(&key) {
+                Some(document) => results.push((idx, BatchItem {
+                    key,
+                    result: Ok(document.clone()),
+                    from_checkpoint: true,
+                    checkpoint_error: None,
+                })),
+                None => pending.push((idx, key, builder)),
+            }
+        }
+
+        let limiter = RateLimiter::new(self.min_interval);
+        let mut sent = stream::iter(pending)
+            .map(|(idx, key, builder)| {
+                let limiter = &limiter;
+                async move {
+                    limiter.wait().await;
+                    (idx, key, send_one(builder).await)
+                }
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some((idx, key, result)) = sent.next().await {
+            let mut checkpoint_error = None;
+            if let (Some(path), Ok(document)) = (&self.checkpoint, &result) {
+                created.insert(key.clone(), document.clone());
+                checkpoint_error = save_checkpoint(path, &created).await.err();
+            }
+            results.push((idx, BatchItem {
+                key,
+                result,
+                from_checkpoint: false,
+                checkpoint_error,
+            }));
+        }
+
+        results.sort_by_key(|(idx, _)| *idx);
+        Ok(results.into_iter().map(|(_, item)| item).collect())
+    }
+}
+
+async fn send_one<R: RecipientSection>(builder: CreateIDocumentBuilder<'_, IDGeneral, SenderInfo, R>) -> Result<IDocument, CreateIDocumentError> {
+    let res = builder.send().await?;
+    match res.data.into_iter().next() {
+        Some(document) if res.success => Ok(document),
+        _ => Err(CreateIDocumentError::Rejected(res.errors)),
+    }
+}
+
+struct RateLimiter {
+    min_interval: Duration,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    async fn wait(&self) {
+        let mut next = self.next.lock().await;
+        time::sleep_until(*next).await;
+        *next = Instant::now() + self.min_interval;
+    }
+}
+
+async fn load_checkpoint<K>(path: &Path) -> io::Result<HashMap<K, IDocument>>
+    where K: Eq + Hash + DeserializeOwned,
+{
+    match fs::read(path).await {
+        Ok(bytes) => {
+            let entries: Vec<(K, IDocument)> = serde_json::from_slice(&bytes)?;
+            Ok(entries.into_iter().collect())
+        },
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Writes to a temporary file first, so a crash mid-write never leaves a
+/// truncated checkpoint behind.
+async fn save_checkpoint<K: Serialize>(path: &Path, created: &HashMap<K, IDocument>) -> io::Result<()> {
+    let entries = created.iter().collect::<Vec<_>>();
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, serde_json::to_vec(&entries)?).await?;
+    fs::rename(tmp_path, path).await
+}
@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::np_client::date_format::id_date_format_option;
+use crate::np_client::en::ENumber;
+use crate::np_client::helper_structs::DocumentID;
+use crate::np_client::{NPClient, NPRequest, res_template::ResponseTemplate};
+
+/// Waybill entry of `InternetDocument.getDocumentList`.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListedDocument {
+    r#ref: DocumentID,
+    int_doc_number: ENumber,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default, alias = "ClientBarcode")]
+    info_reg_client_barcodes: Option<String>,
+}
+
+impl ListedDocument {
+    pub fn document_id(&self) -> DocumentID {
+        self.r#ref
+    }
+
+    pub fn int_doc_number(&self) -> &ENumber {
+        &self.int_doc_number
+    }
+
+    /// Kept as a raw string: documents created outside the API may carry
+    /// barcodes `ClientBarcode` would reject.
+    pub fn client_barcode(&self) -> Option<&str> {
+        self.info_reg_client_barcodes.as_deref()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetDocumentListBuilder<'cli> {
+    #[serde(skip)]
+    client: &'cli NPClient,
+
+    #[serde(with = "id_date_format_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_time_from: Option<NaiveDate>,
+
+    #[serde(with = "id_date_format_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_time_to: Option<NaiveDate>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u16>,
+
+    /// Without it the API returns a single page.
+    get_full_list: &'static str,
+}
+
+impl<'cli> GetDocumentListBuilder<'cli> {
+    pub fn new(client: &'cli NPClient) -> Self {
+        Self {
+            client,
+            date_time_from: None,
+            date_time_to: None,
+            page: None,
+            get_full_list: "1",
+        }
+    }
+
+    pub fn date_time_from(mut self, date: NaiveDate) -> Self {
+        self.date_time_from = Some(date);
+        self
+    }
+
+    pub fn date_time_to(mut self, date: NaiveDate) -> Self {
+        self.date_time_to = Some(date);
+        self
+    }
+
+    /// Requests a single page instead of the full list.
+    pub fn page(mut self, page: u16) -> Self {
+        self.page = Some(page);
+        self.get_full_list = "0";
+        self
+    }
+
+    pub async fn send(self) -> Result<ResponseTemplate<ListedDocument>, reqwest::Error> {
+        let request = NPRequest {
+            api_key: &self.client.api_key,
+            model_name: "InternetDocument",
+            called_method: "getDocumentList",
+            method_properties: &self
+        };
+
+        let url = self.client.base_url.to_owned();
+        self.client.http_client
+            .post(url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::str::FromStr;
+
+use phonenumber::country::Id::UA;
+use phonenumber::metadata::DATABASE;
+use phonenumber::{Mode, PhoneNumber, Type};
+use serde::{Deserialize, Serialize};
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum PhoneParsingError {
+    #[error("Value is not a valid phone number")]
+    InvalidNumber,
+    #[error("Value is not a Ukrainian mobile number")]
+    NotUkrainianMobile,
+}
+
+/// Phone number in the `380XXXXXXXXX` form the API expects.
+///
+/// `FromStr` accepts `+380…`, `380…` and `0…` notations of Ukrainian mobile
+/// numbers only; use [`UaPhone::parse_any`] to allow other numbers as well.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String")]
+pub struct UaPhone(String);
+
+impl UaPhone {
+    /// Parses any valid number, keeping foreign ones in international
+    /// digits-only form.
+    pub fn parse_any(value: &str) -> Result<Self, PhoneParsingError> {
+        let number = phonenumber::parse(Some(UA), value)
+            .map_err(|_| PhoneParsingError::InvalidNumber)?;
+
+        Self::from_number(number, true)
+    }
+
+    fn from_number(number: PhoneNumber, allow_any: bool) -> Result<Self, PhoneParsingError> {
+        if !number.is_valid() {
+            return Err(PhoneParsingError::InvalidNumber);
+        }
+        if !allow_any
+            && (number.country().id() != Some(UA) || number.number_type(&DATABASE) != Type::Mobile)
+        {
+            return Err(PhoneParsingError::NotUkrainianMobile);
+        }
+
+        let e164 = number.format().mode(Mode::E164).to_string();
+        Ok(Self(e164.trim_start_matches('+').to_owned()))
+    }
+}
+
+impl FromStr for UaPhone {
+    type Err = PhoneParsingError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let number = phonenumber::parse(Some(UA), value)
+            .map_err(|_| PhoneParsingError::InvalidNumber)?;
+
+        Self::from_number(number, false)
+    }
+}
+
+impl TryFrom<PhoneNumber> for UaPhone {
+    type Error = PhoneParsingError;
+
+    fn try_from(number: PhoneNumber) -> Result<Self, Self::Error> {
+        Self::from_number(number, false)
+    }
+}
+
+/// Reads back what [`UaPhone`] serializes to: a Ukrainian mobile number in
+/// any notation `FromStr` takes, or any other valid number in international
+/// digits-only form, as made by [`UaPhone::parse_any`].
+impl TryFrom<String> for UaPhone {
+    type Error = PhoneParsingError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value).or_else(|_| Self::parse_any(&format!("+{value}")))
+    }
+}
+
+impl fmt::Display for UaPhone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for UaPhone {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
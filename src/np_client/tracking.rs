@@ -8,17 +8,20 @@ use super::{NPClient, NPRequest};
 use super::date_format::{common_date_format, np_date_format};
 use super::deserializer::{deserialize_f32_option, deserialize_u16_option};
 use super::en::ENumber;
-use super::helper_structs::{CounterpartyRole, CounterpartyType, PaymentMethod, ServiceType};
+use super::phone::UaPhone;
+use super::helper_structs::{CityID, CounterpartyRole, CounterpartyType, DocumentID, PaymentMethod, ServiceType, SettlementID, WarehouseID};
 
+#[serde_as]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Document {
     document_number: ENumber,
-    phone: String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    phone: Option<UaPhone>,
 }
 
 impl Document {
-    pub fn new(en: ENumber, phone: String) -> Self {
+    pub fn new(en: ENumber, phone: Option<UaPhone>) -> Self {
         Self {
             document_number: en,
             phone,
@@ -26,6 +29,8 @@ impl Document {
     }
 }
 
+// Response parts `TrackingDoc` doesn't read yet.
+#[allow(dead_code)]
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -38,17 +43,18 @@ struct Redelivery {
     redelivery_payer: Option<String>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 struct SenderInfo {
     city_sender: String,
     counterparty_sender_type: CounterpartyType,
     phone_sender: Option<String>,
-    warehouse_sender_internet_address_ref: Uuid,
+    warehouse_sender_internet_address_ref: WarehouseID,
     warehouse_sender_address: String,
     warehouse_sender: String,
-    ref_city_sender: Uuid,
-    ref_settlement_sender: Uuid,
+    ref_city_sender: CityID,
+    ref_settlement_sender: SettlementID,
     sender_address: String,
     sender_full_name_e_w: String,
 }
@@ -65,14 +71,14 @@ struct RecipientInfo {
     recipient_full_name_e_w: Option<String>,
     city_recipient: String,
     warehouse_recipient: String,
-    warehouse_recipient_internet_address_ref: Uuid,
+    warehouse_recipient_internet_address_ref: WarehouseID,
     recipient_address: String,
     #[serde_as(as = "NoneAsEmptyString")]
     counterparty_recipient_description: Option<String>,
-    ref_settlement_recipient: Uuid,
-    ref_city_recipient: Uuid,
+    ref_settlement_recipient: SettlementID,
+    ref_city_recipient: CityID,
     recipient_warehouse_type_ref: Uuid,
-    warehouse_recipient_ref: Uuid,
+    warehouse_recipient_ref: WarehouseID,
     #[serde_as(as = "NoneAsEmptyString")]
     loyalty_card_recipient: Option<String>,
     warehouse_recipient_number: Option<u16>,
@@ -178,7 +184,7 @@ pub struct TrackingDoc {
     status: Option<String>,
     ///CODE
     status_code: Option<String>,
-    ref_e_w: Uuid,
+    ref_e_w: DocumentID,
     backward_delivery_sub_types_actions: Option<String>,
     backward_delivery_sub_types_services: Option<String>,
     #[serde(with = "common_date_format")]
@@ -229,6 +235,26 @@ pub struct TrackingDoc {
     free_shipping: Option<String>,
 }
 
+impl TrackingDoc {
+    pub fn number(&self) -> &ENumber {
+        &self.number
+    }
+
+    /// Kept as a plain string: barcodes set outside of this crate may not
+    /// pass `ClientBarcode` validation.
+    pub fn client_barcode(&self) -> Option<&str> {
+        self.client_barcode.as_deref()
+    }
+
+    pub fn document_id(&self) -> DocumentID {
+        self.ref_e_w
+    }
+
+    pub fn city_recipient(&self) -> CityID {
+        self.recipient_info.ref_city_recipient
+    }
+}
+
 pub struct TrackingHandler<'c> {
     client: &'c NPClient,
 }
@@ -238,7 +264,7 @@ impl<'cli> TrackingHandler<'cli> {
         Self { client }
     }
 
-    pub fn track_parcel(&self, en: ENumber, phone: String) -> TrackParcelBuilder<'cli> {
+    pub fn track_parcel(&self, en: ENumber, phone: Option<UaPhone>) -> TrackParcelBuilder<'cli> {
         TrackParcelBuilder::new(self.client, en, phone)
     }
 
@@ -254,7 +280,7 @@ pub struct TrackParcelBuilder<'cli> {
 }
 
 impl<'cli> TrackParcelBuilder<'cli> {
-    pub fn new(client: &'cli NPClient, en: ENumber, phone_number: String) -> Self {
+    pub fn new(client: &'cli NPClient, en: ENumber, phone_number: Option<UaPhone>) -> Self {
         Self {
             client,
             documents: vec![Document::new(
@@ -264,7 +290,7 @@ impl<'cli> TrackParcelBuilder<'cli> {
         }
     }
 
-    pub fn add_document(mut self, en: ENumber, phone_number: String) -> Self {
+    pub fn add_document(mut self, en: ENumber, phone_number: Option<UaPhone>) -> Self {
         self.documents.push(
             Document::new(en, phone_number)
         );
@@ -278,7 +304,7 @@ impl<'cli> TrackParcelBuilder<'cli> {
             called_method: "getStatusDocuments",
             method_properties: &self
         };
-        let url = (&self.client.base_url).to_owned();
+        let url = self.client.base_url.to_owned();
         self.client.http_client
             .post(url)
             .json(&request)
@@ -1,6 +1,17 @@
+mod areas;
 mod cities;
+mod common;
+mod contact_persons;
+mod counterparty_addresses;
 mod create_counterparty;
 mod settlement_search;
+mod streets;
 mod tracking;
+mod update_counterparty;
 mod warehouses;
 mod create_internet_document;
+mod warehouse_directory;
+mod warehouse_index;
+mod warehouse_schedule;
+mod place_search;
+mod address_autocomplete;
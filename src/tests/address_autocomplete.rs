@@ -0,0 +1,257 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::np_client::address::autocomplete::{AutocompleteError, SuggestionKind};
+use crate::np_client::helper_structs::{AddressID, CityID, CounterpartyID, StreetRef};
+use crate::np_client::NPClient;
+
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CITY_REF: &str = "db5c88d4-391c-11dd-90d9-001a92567626";
+
+async fn mock(mock_server: &MockServer, body: Value, response: &'static str, expected: u64) {
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(response, "application/json"))
+        .expect(expected)
+        .mount(mock_server)
+        .await;
+}
+
+async fn client(mock_server: &MockServer) -> NPClient {
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+    np_client
+}
+
+#[tokio::test]
+async fn settlements_cached_per_prefix() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({
+            "calledMethod": "searchSettlements",
+            "methodProperties": { "CityName": "немир", "Limit": 20 }
+        }),
+        include_str!("resources/settlement_search_response.json"),
+        1,
+    ).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::ZERO);
+    let session = autocomplete.session();
+
+    let found = session.settlements(" Немир").await.unwrap();
+    assert_eq!(found.len(), 6);
+    assert_eq!(found[0].label(), "м. Немирів, Немирівський р-н, Вінницька обл.");
+    assert_eq!(found[0].city_ref(), CityID::from_str("69da4154-3f5d-11de-b509-001d92f78698").unwrap());
+    assert!(matches!(found[0].kind(), SuggestionKind::Settlement { address_delivery_allowed: true, .. }));
+
+    // The first answer was complete, so the longer query is narrowed locally.
+    let narrowed = session.settlements("немирівк").await.unwrap();
+    assert_eq!(narrowed.len(), 3);
+    assert_eq!(session.settlements("немир").await.unwrap(), found);
+    assert!(session.settlements("  ").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn settlements_debounced() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({
+            "calledMethod": "searchSettlements",
+            "methodProperties": { "CityName": "немир" }
+        }),
+        include_str!("resources/settlement_search_response.json"),
+        1,
+    ).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::from_millis(100));
+    let session = autocomplete.session();
+
+    let (stale, latest) = tokio::join!(
+        session.settlements("нем"),
+        async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            session.settlements("немир").await
+        },
+    );
+    assert!(matches!(stale, Err(AutocompleteError::Superseded)));
+    assert_eq!(latest.unwrap().len(), 6);
+}
+
+#[tokio::test]
+async fn warehouse_resolved_without_request() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({
+            "calledMethod": "getWarehouses",
+            "methodProperties": { "CityRef": CITY_REF, "FindByString": "відділення", "Limit": 20 }
+        }),
+        include_str!("resources/warehouses_response.json"),
+        1,
+    ).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::ZERO);
+    let session = autocomplete.session();
+    let city_ref = CityID::from_str(CITY_REF).unwrap();
+
+    let found = session.warehouses(city_ref, "Відділення").await.unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(session.warehouses(city_ref, "відділення №2").await.unwrap(), found[1..]);
+
+    let resolved = autocomplete.resolve_warehouse(&found[0]).unwrap();
+    assert_eq!(resolved.city(), city_ref);
+    assert_eq!(resolved.address(), AddressID::from_str("1ec09d2d-e1c2-11e3-8c4a-0050568002cf").unwrap());
+}
+
+#[tokio::test]
+async fn street_resolved_into_saved_address() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock_settlements_with_streets(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({
+            "calledMethod": "getStreet",
+            "methodProperties": { "CityRef": CITY_REF, "FindByString": "хрещ" }
+        }),
+        include_str!("resources/streets_response.json"),
+        1,
+    ).await;
+    mock(
+        &mock_server,
+        json!({
+            "modelName": "Address",
+            "calledMethod": "save",
+            "methodProperties": {
+                "CounterpartyRef": "56300fb9-cbd3-11e4-bdb5-005056801329",
+                "StreetRef": "a7dea32d-5b0f-11e7-8d60-005056881c6b",
+                "BuildingNumber": "22",
+                "Flat": "5"
+            }
+        }),
+        include_str!("resources/save_address_response.json"),
+        1,
+    ).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::ZERO);
+    let session = autocomplete.session();
+    let city_ref = CityID::from_str(CITY_REF).unwrap();
+
+    let settlement = session.settlements("немир").await.unwrap().remove(0);
+    let found = session.streets(&settlement, "Хрещ").await.unwrap();
+    assert_eq!(found[0].label(), "вул. Хрещатик");
+    assert_eq!(found[0].kind(), SuggestionKind::Street(StreetRef::from_str("a7dea32d-5b0f-11e7-8d60-005056881c6b").unwrap()));
+    assert!(matches!(autocomplete.resolve_warehouse(&found[0]), Err(AutocompleteError::UnexpectedKind)));
+
+    let resolved = autocomplete
+        .resolve_street(
+            &found[0],
+            CounterpartyID::from_str("56300fb9-cbd3-11e4-bdb5-005056801329").unwrap(),
+            "22".to_owned(),
+            Some("5".to_owned()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resolved.city(), city_ref);
+    assert_eq!(resolved.address(), AddressID::from_str("503702df-cd4c-11e4-bdb5-005056801329").unwrap());
+}
+
+#[tokio::test]
+async fn streets_refused_without_street_list() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({ "calledMethod": "searchSettlements" }),
+        include_str!("resources/settlement_search_response.json"),
+        1,
+    ).await;
+    mock(&mock_server, json!({ "calledMethod": "getStreet" }), include_str!("resources/streets_response.json"), 0).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::ZERO);
+    let session = autocomplete.session();
+
+    let settlement = session.settlements("немир").await.unwrap().remove(0);
+    assert!(matches!(session.streets(&settlement, "Хрещ").await, Err(AutocompleteError::NoStreets)));
+}
+
+#[tokio::test]
+async fn sessions_debounced_separately() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({ "calledMethod": "searchSettlements" }),
+        include_str!("resources/settlement_search_response.json"),
+        2,
+    ).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::from_millis(100));
+    let (first, second) = (autocomplete.session(), autocomplete.session());
+
+    let (one, other) = tokio::join!(
+        first.settlements("нем"),
+        async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            second.settlements("київ").await
+        },
+    );
+    assert_eq!(one.unwrap().len(), 6);
+    assert_eq!(other.unwrap().len(), 6);
+}
+
+#[tokio::test]
+async fn cache_bounded_by_capacity_and_ttl() {
+    let mock_server = MockServer::start().await;
+    let np_client = client(&mock_server).await;
+    mock(
+        &mock_server,
+        json!({ "calledMethod": "searchSettlements", "methodProperties": { "CityName": "немир" } }),
+        include_str!("resources/settlement_search_response.json"),
+        3,
+    ).await;
+    mock(
+        &mock_server,
+        json!({ "calledMethod": "searchSettlements", "methodProperties": { "CityName": "київ" } }),
+        include_str!("resources/settlement_search_response.json"),
+        1,
+    ).await;
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::ZERO).cache_capacity(1);
+    let session = autocomplete.session();
+
+    session.settlements("немир").await.unwrap();
+    session.settlements("немир").await.unwrap();
+    // Evicts the first query.
+    session.settlements("київ").await.unwrap();
+    session.settlements("немир").await.unwrap();
+
+    let autocomplete = np_client.address().autocomplete().debounce(Duration::ZERO).cache_ttl(Duration::ZERO);
+    autocomplete.session().settlements("немир").await.unwrap();
+}
+
+async fn mock_settlements_with_streets(mock_server: &MockServer) {
+    let mut body: Value = serde_json::from_str(include_str!("resources/settlement_search_response.json")).unwrap();
+    let settlement = &mut body["data"][0]["Addresses"][0];
+    settlement["StreetsAvailability"] = json!(true);
+    settlement["DeliveryCity"] = json!(CITY_REF);
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "searchSettlements" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, helper_structs::AreaID};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn get_areas_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "getAreas",
+        "methodProperties": {}
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/areas_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .address()
+        .get_areas()
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data[1].description(), "Львівська");
+}
+
+#[tokio::test]
+async fn get_settlement_regions_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "getSettlementCountryRegion",
+        "methodProperties": {
+            "AreaRef": "dcaadb64-4b33-11e4-ab6d-005056801329"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/settlement_regions_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .address()
+        .get_settlement_regions(AreaID::from_str("dcaadb64-4b33-11e4-ab6d-005056801329").unwrap())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data.len(), 2);
+    assert!(res.data[0].areas_center().is_none());
+}
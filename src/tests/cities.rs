@@ -1,4 +1,6 @@
-use crate::np_client::NPClient;
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, helper_structs::{AreaID, CityID}};
 
 use serde_json::json;
 use wiremock::{
@@ -103,3 +105,31 @@ async fn get_cities_request_invalid_string() {
     assert_eq!(res.data.len(), 0);
     assert_eq!(res.error_codes.len(), 1);
 }
+
+#[tokio::test]
+async fn city_ref_feeds_typed_id() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/cities_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .address()
+        .get_cities()
+        .send()
+        .await
+        .unwrap();
+
+    let city_id = CityID::from(&res.data[0]);
+    assert_eq!(city_id, CityID::from_str("db5c88f5-391c-11dd-90d9-001a92567626").unwrap());
+    assert_eq!(res.data[0].area(), AreaID::from_str("71508134-9b87-11de-822f-000c2965ae0e").unwrap());
+}
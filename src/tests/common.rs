@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, helper_structs::{CargoType, CityID, CounterpartyRole}};
+
+use chrono::{NaiveDate, NaiveTime};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn cargo_types_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Common",
+        "calledMethod": "getCargoTypes",
+        "methodProperties": {}
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/cargo_types_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .common()
+        .get_cargo_types()
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    let cargo_types = res.data
+        .iter()
+        .map(|entry| entry.parse::<CargoType>())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(cargo_types.len(), 5);
+    assert!(cargo_types.contains(&CargoType::Parcel));
+}
+
+#[tokio::test]
+async fn pack_list_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Common",
+        "calledMethod": "getPackList",
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/pack_list_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .common()
+        .get_packs()
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data.len(), 2);
+    assert_eq!(res.data[0].description(), "Коробка (0,5 кг) пласка");
+    assert_eq!(res.data[0].dimensions(), (Some(260.0), Some(170.0), Some(10.0)));
+    assert_eq!(res.data[0].type_of_packing(), None);
+}
+
+#[test]
+fn counterparty_role_spellings() {
+    assert_eq!(CounterpartyRole::try_from("Recipient"), Ok(CounterpartyRole::Recipient));
+    assert_eq!(CounterpartyRole::try_from("Recepient"), Ok(CounterpartyRole::Recipient));
+    assert!(CounterpartyRole::try_from("Receiver").is_err());
+}
+
+#[tokio::test]
+async fn time_intervals_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Common",
+        "calledMethod": "getTimeIntervals",
+        "methodProperties": {
+            "RecipientCityRef": "8d5a980d-391c-11dd-90d9-001a92567626",
+            "DateTime": "20.10.2023"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/time_intervals_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .common()
+        .get_time_intervals(CityID::from_str("8d5a980d-391c-11dd-90d9-001a92567626").unwrap())
+        .date(NaiveDate::from_ymd_opt(2023, 10, 20).unwrap())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data.len(), 4);
+    assert_eq!(res.data[1].code().as_ref(), "CityDeliveryTimeInterval2");
+    assert_eq!(res.data[1].start(), NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+    assert_eq!(res.data[1].end(), NaiveTime::from_hms_opt(15, 0, 0).unwrap());
+}
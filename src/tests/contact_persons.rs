@@ -0,0 +1,102 @@
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, phone::UaPhone, helper_structs::{ContactPersonID, CounterpartyID}};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn create_contact_person_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "ContactPerson",
+        "calledMethod": "save",
+        "methodProperties": {
+            "CounterpartyRef": "57f2c3c2-596f-11ee-a60f-48df37b921db",
+            "FirstName": "Тарас",
+            "MiddleName": "Григорович",
+            "LastName": "Шевченко",
+            "Phone": "380991234567",
+            "Email": "manager@test.com"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/save_contact_person_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .create_contact_person(CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap())
+        .name("Тарас".to_owned(), "Григорович".to_owned(), "Шевченко".to_owned())
+        .phone(UaPhone::from_str("380991234567").unwrap())
+        .email("manager@test.com".to_owned())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(
+        res.data[0].contact_person_id(),
+        ContactPersonID::from_str("d8fd6cc9-6c8b-11ee-a60f-48df37b921db").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn update_contact_person_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "ContactPerson",
+        "calledMethod": "update",
+        "methodProperties": {
+            "Ref": "d8fd6cc9-6c8b-11ee-a60f-48df37b921db",
+            "CounterpartyRef": "57f2c3c2-596f-11ee-a60f-48df37b921db",
+            "FirstName": "Тарас",
+            "MiddleName": "Григорович",
+            "LastName": "Шевченко",
+            "Phone": "380991234567"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/save_contact_person_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .update_contact_person(
+            CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap(),
+            ContactPersonID::from_str("d8fd6cc9-6c8b-11ee-a60f-48df37b921db").unwrap(),
+        )
+        .phone(UaPhone::from_str("380991234567").unwrap())
+        .name("Тарас".to_owned(), "Григорович".to_owned(), "Шевченко".to_owned())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, helper_structs::{AddressID, CounterpartyID, StreetRef}};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn create_address_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "save",
+        "methodProperties": {
+            "CounterpartyRef": "580c30f7-596f-11ee-a60f-48df37b921db",
+            "StreetRef": "d8364179-4149-11dd-9198-001d60451983",
+            "BuildingNumber": "32",
+            "Flat": "12",
+            "Note": "Коментар"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/save_address_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .create_address(
+            CounterpartyID::from_str("580c30f7-596f-11ee-a60f-48df37b921db").unwrap(),
+            StreetRef::from_str("d8364179-4149-11dd-9198-001d60451983").unwrap(),
+            "32".to_owned(),
+        )
+        .flat("12".to_owned())
+        .note("Коментар".to_owned())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(
+        res.data[0].address_id(),
+        AddressID::from_str("503702df-cd4c-11e4-bdb5-005056801329").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn delete_address_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "delete",
+        "methodProperties": {
+            "Ref": "503702df-cd4c-11e4-bdb5-005056801329"
+        }
+    });
+
+    let res = r#"
+        {
+            "success": true,
+            "data": [
+                {
+                    "Ref": "503702df-cd4c-11e4-bdb5-005056801329"
+                }
+            ],
+            "errors": [],
+            "warnings": [],
+            "info": [],
+            "messageCodes": [],
+            "errorCodes": [],
+            "warningCodes": [],
+            "infoCodes": []
+        }
+    "#;
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(res, "application/json"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .delete_address(AddressID::from_str("503702df-cd4c-11e4-bdb5-005056801329").unwrap())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
@@ -1,4 +1,11 @@
-use crate::np_client::{NPClient, helper_structs::{CounterpartyType, CounterpartyRole}};
+use std::str::FromStr;
+
+use crate::np_client::{
+    NPClient,
+    edrpou::{Edrpou, EdrpouParsingError},
+    phone::{PhoneParsingError, UaPhone},
+    helper_structs::{CityID, CounterpartyType, CounterpartyRole, OwnershipFormID},
+};
 
 use serde_json::json;
 use wiremock::{
@@ -42,7 +49,7 @@ async fn create_counterparty_request_ok() {
         .counterparty()
         .create_counterparty()
         .name("Богдан".to_owned(), "Ігор".to_owned(), "Антонич".to_owned())
-        .phone("380997979780".to_owned())
+        .phone(UaPhone::from_str("380997979780").unwrap())
         .email("test@test.com".to_owned())
         .cp_type(CounterpartyType::PrivatePerson)
         .role(CounterpartyRole::Recipient)
@@ -52,3 +59,81 @@ async fn create_counterparty_request_ok() {
     assert!(res.is_ok());
     assert!(res.unwrap().success);
 }
+
+#[tokio::test]
+async fn create_organization_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "apiKey": "",
+        "modelName": "Counterparty",
+        "calledMethod": "save",
+        "methodProperties": {
+            "EDRPOU": "40075815",
+            "OwnershipForm": "7f0f351d-2519-11df-be9a-000c291af1b3",
+            "CounterpartyType": "Organization",
+            "CounterpartyProperty": "Recipient",
+            "CityRef": "db5c88d4-391c-11dd-90d9-001a92567626"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_organization_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .create_organization()
+        .edrpou(Edrpou::from_str("40075815").unwrap())
+        .ownership_form(OwnershipFormID::from_str("7f0f351d-2519-11df-be9a-000c291af1b3").unwrap())
+        .role(CounterpartyRole::Recipient)
+        .city_ref(CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data[0].counterparty_type(), CounterpartyType::Organization);
+}
+
+#[test]
+fn edrpou_validation() {
+    assert!(Edrpou::from_str("40075815").is_ok());
+    assert!(Edrpou::from_str("14360570").is_ok());
+    assert!(Edrpou::from_str("00032129").is_ok());
+    assert_eq!(Edrpou::from_str("40075816"), Err(EdrpouParsingError::InvalidChecksum));
+    assert_eq!(Edrpou::from_str("4007581"), Err(EdrpouParsingError::InvalidLength));
+    assert_eq!(Edrpou::from_str("4007581a"), Err(EdrpouParsingError::InvalidSymbols));
+    assert!(serde_json::from_value::<Edrpou>(json!("40075815")).is_ok());
+    assert!(serde_json::from_value::<Edrpou>(json!("40075816")).is_err());
+}
+
+#[test]
+fn ua_phone_normalization() {
+    let expected = UaPhone::from_str("380997979780").unwrap();
+    assert_eq!(UaPhone::from_str("+380997979780").unwrap(), expected);
+    assert_eq!(UaPhone::from_str("0997979780").unwrap(), expected);
+    assert_eq!(UaPhone::from_str("+38 (099) 797-97-80").unwrap(), expected);
+    assert_eq!(expected.as_ref(), "380997979780");
+
+    assert_eq!(UaPhone::from_str("0441234567"), Err(PhoneParsingError::NotUkrainianMobile));
+    assert_eq!(UaPhone::from_str("+48501234567"), Err(PhoneParsingError::NotUkrainianMobile));
+    assert_eq!(UaPhone::parse_any("+48501234567").unwrap().as_ref(), "48501234567");
+    assert_eq!(UaPhone::from_str("380123456787"), Err(PhoneParsingError::InvalidNumber));
+
+    let foreign = UaPhone::parse_any("+48501234567").unwrap();
+    let value = serde_json::to_value(&foreign).unwrap();
+    assert_eq!(serde_json::from_value::<UaPhone>(value).unwrap(), foreign);
+    assert!(serde_json::from_value::<UaPhone>(json!("380660000000")).is_ok());
+    assert!(serde_json::from_value::<UaPhone>(json!("12345")).is_err());
+}
@@ -1,9 +1,14 @@
 use std::str::FromStr;
+use std::time::Duration;
 
-use crate::np_client::{NPClient, helper_structs::{CounterpartyRole, PaymentMethod, CargoType, ServiceType, CityID, CounterpartyID, ContactPersonID, AddressID}};
+use crate::np_client::address::warehouses::Warehouse;
+use crate::np_client::internet_document::{BackwardDelivery, CreateIDocumentBuilder, CreateIDocumentError, IDGeneral, IDocumentViolation, NoRecipientInfo, RecepientInfo, RecipientWarehouseError, Seat, SeatError, SendOutcome, SenderInfo};
+use crate::np_client::date_format::kyiv_now;
+use crate::np_client::en::ClientBarcode;
+use crate::np_client::res_template::ResponseTemplate as NPResponseTemplate;
+use crate::np_client::{NPClient, phone::UaPhone, helper_structs::{CounterpartyRole, CounterpartyType, PaymentMethod, CargoType, ServiceType, CityID, CounterpartyID, ContactPersonID, AddressID, PackID, SettlementID, SettlementStreetID, TimeIntervalCode}};
 
-use chrono::NaiveDate;
-use phonenumber::country::Id::UA;
+use chrono::{Days, NaiveDate};
 use serde_json::json;
 use wiremock::{
     matchers::{body_partial_json, method, path},
@@ -23,7 +28,7 @@ async fn create_internet_document_ok() {
         "methodProperties": {
             "PayerType": "Sender",
             "PaymentMethod": "Cash",
-            "DateTime": "11.10.2023",
+            "DateTime": kyiv_now().date().format("%d.%m.%Y").to_string(),
             "CargoType": "Parcel",
             "Weight": "0.5",
             "ServiceType": "WarehouseDoors",
@@ -34,12 +39,12 @@ async fn create_internet_document_ok() {
             "Sender": "57f2c3c2-596f-11ee-a60f-48df37b921db",
             "SenderAddress": "1ec09d2d-e1c2-11e3-8c4a-0050568002cf",
             "ContactSender": "57f35831-596f-11ee-a60f-48df37b921db",
-            "SendersPhone": "+380660000001",
+            "SendersPhone": "380660000001",
             "CityRecipient": "db5c88d4-391c-11dd-90d9-001a92567626",
             "Recipient": "580c30f7-596f-11ee-a60f-48df37b921db",
             "RecipientAddress": "39633d8b-645f-11ee-a60f-48df37b921db",
             "ContactRecipient": "c1ffd9b4-643e-11ee-a60f-48df37b921db",
-            "RecipientsPhone": "+380660000000",
+            "RecipientsPhone": "380660000000",
         }
     });
 
@@ -60,7 +65,7 @@ async fn create_internet_document_ok() {
         .general_info(
             CounterpartyRole::Sender,
             PaymentMethod::Cash,
-            NaiveDate::from_ymd_opt(2023, 10, 11).unwrap(),
+            kyiv_now().date(),
             CargoType::Parcel,
             0.5,
             ServiceType::WarehouseDoors,
@@ -73,19 +78,802 @@ async fn create_internet_document_ok() {
             CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap(),
             AddressID::from_str("1ec09d2d-e1c2-11e3-8c4a-0050568002cf").unwrap(),
             ContactPersonID::from_str("57f35831-596f-11ee-a60f-48df37b921db").unwrap(),
-            phonenumber::parse(Some(UA), "380660000001").unwrap()
-            
+            UaPhone::from_str("+380660000001").unwrap()
+        )
+        .recipient_info(
+            CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap(),
+            CounterpartyID::from_str("580c30f7-596f-11ee-a60f-48df37b921db").unwrap(),
+            AddressID::from_str("39633d8b-645f-11ee-a60f-48df37b921db").unwrap(),
+            ContactPersonID::from_str("c1ffd9b4-643e-11ee-a60f-48df37b921db").unwrap(),
+            UaPhone::from_str("0660000000").unwrap()
         )
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
+
+fn complete_builder(np_client: &NPClient) -> CreateIDocumentBuilder<'_, IDGeneral, SenderInfo, RecepientInfo> {
+    sender_builder(np_client, 15.0)
         .recipient_info(
             CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap(),
             CounterpartyID::from_str("580c30f7-596f-11ee-a60f-48df37b921db").unwrap(),
             AddressID::from_str("39633d8b-645f-11ee-a60f-48df37b921db").unwrap(),
             ContactPersonID::from_str("c1ffd9b4-643e-11ee-a60f-48df37b921db").unwrap(),
-            phonenumber::parse(Some(UA), "380660000000").unwrap()
+            UaPhone::from_str("380660000000").unwrap()
+        )
+}
+
+fn sender_builder(np_client: &NPClient, weight: f32) -> CreateIDocumentBuilder<'_, IDGeneral, SenderInfo, NoRecipientInfo> {
+    np_client
+        .i_document()
+        .create_document()
+        .general_info(
+            CounterpartyRole::Sender,
+            PaymentMethod::Cash,
+            kyiv_now().date(),
+            CargoType::Parcel,
+            weight,
+            ServiceType::WarehouseWarehouse,
+            2,
+            "посилка".to_owned(),
+            120,
         )
+        .sender_info(
+            CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap(),
+            CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap(),
+            AddressID::from_str("1ec09d2d-e1c2-11e3-8c4a-0050568002cf").unwrap(),
+            ContactPersonID::from_str("57f35831-596f-11ee-a60f-48df37b921db").unwrap(),
+            UaPhone::from_str("380660000001").unwrap()
+        )
+}
+
+fn warehouses() -> Vec<Warehouse> {
+    let res: NPResponseTemplate<Warehouse> = serde_json::from_str(
+        include_str!("resources/warehouses_response.json")
+    ).unwrap();
+    res.data
+}
+
+#[tokio::test]
+async fn create_internet_document_with_seats() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "InternetDocument",
+        "calledMethod": "save",
+        "methodProperties": {
+            "SeatsAmount": "2",
+            "OptionsSeat": [
+                {
+                    "volumetricWidth": "30",
+                    "volumetricLength": "40",
+                    "volumetricHeight": "50",
+                    "weight": "10",
+                    "specialCargo": 0
+                },
+                {
+                    "volumetricWidth": "20",
+                    "volumetricLength": "20",
+                    "volumetricHeight": "20",
+                    "weight": "5",
+                    "specialCargo": 1
+                }
+            ]
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let warehouses = warehouses();
+    let res = complete_builder(&np_client)
+        .options_seat_for_warehouse(
+            vec![
+                Seat::new(30.0, 40.0, 50.0, 10.0),
+                Seat::new(20.0, 20.0, 20.0, 5.0).special_cargo(true),
+            ],
+            &warehouses[1],
+        )
+        .unwrap()
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
+
+#[test]
+fn seats_checked_against_warehouse_limits() {
+    let warehouses = warehouses();
+
+    // Rotated to fit the 120x120x120 limit of the second warehouse.
+    assert!(Seat::new(30.0, 110.0, 50.0, 10.0).check_sending_limits(&warehouses[1]).is_ok());
+    assert_eq!(
+        Seat::new(130.0, 50.0, 50.0, 10.0).check_sending_limits(&warehouses[1]),
+        Err(vec![SeatError::TooLarge])
+    );
+    assert_eq!(
+        Seat::new(100.0, 100.0, 100.0, 1200.0).check_sending_limits(&warehouses[0]),
+        Err(vec![SeatError::TooHeavy])
+    );
+    assert_eq!(
+        Seat::new(700.0, 50.0, 50.0, 1200.0).check_sending_limits(&warehouses[0]),
+        Err(vec![SeatError::TooLarge, SeatError::TooHeavy])
+    );
+
+    let np_client = NPClient::default().unwrap();
+    let errors = complete_builder(&np_client)
+        .options_seat_for_warehouse(
+            vec![
+                Seat::new(30.0, 40.0, 50.0, 10.0),
+                Seat::new(200.0, 20.0, 20.0, 5.0),
+            ],
+            &warehouses[1],
+        )
+        .unwrap_err();
+    assert_eq!(errors, vec![(1, SeatError::TooLarge)]);
+}
+
+#[tokio::test]
+async fn create_internet_document_with_backward_delivery() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "InternetDocument",
+        "calledMethod": "save",
+        "methodProperties": {
+            "BackwardDeliveryData": [
+                {
+                    "PayerType": "Recipient",
+                    "CargoType": "Money",
+                    "RedeliveryString": "4552"
+                }
+            ]
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .backward_delivery(BackwardDelivery::money(CounterpartyRole::Recipient, 4552.0))
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
+
+#[tokio::test]
+async fn create_internet_document_simple_recipient() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "InternetDocument",
+        "calledMethod": "save",
+        "methodProperties": {
+            "NewAddress": "1",
+            "RecipientName": "Антонич Богдан Ігор",
+            "RecipientType": "PrivatePerson",
+            "RecipientsPhone": "380660000000",
+            "CityRecipient": "db5c88d4-391c-11dd-90d9-001a92567626",
+            "RecipientAddress": "1ec09d2d-e1c2-11e3-8c4a-0050568002cf"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let warehouses = warehouses();
+    let res = sender_builder(&np_client, 15.0)
+        .simple_recipient_info(
+            "Антонич Богдан Ігор".to_owned(),
+            UaPhone::from_str("380660000000").unwrap(),
+            &warehouses[0],
+        )
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
+
+#[tokio::test]
+async fn create_internet_document_simple_address_recipient() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "InternetDocument",
+        "calledMethod": "save",
+        "methodProperties": {
+            "NewAddress": "1",
+            "RecipientName": "Антонич Богдан Ігор",
+            "RecipientType": "PrivatePerson",
+            "RecipientsPhone": "380660000000",
+            "RecipientCityName": "e718a680-4b33-11e4-ab6d-005056801329",
+            "RecipientAddressName": "c8025d1c-b36a-11e4-a77a-005056887b8d",
+            "RecipientHouse": "22",
+            "RecipientFlat": "5"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = sender_builder(&np_client, 15.0)
+        .simple_address_recipient_info(
+            "Антонич Богдан Ігор".to_owned(),
+            UaPhone::from_str("380660000000").unwrap(),
+            SettlementID::from_str("e718a680-4b33-11e4-ab6d-005056801329").unwrap(),
+            SettlementStreetID::from_str("c8025d1c-b36a-11e4-a77a-005056887b8d").unwrap(),
+            "22".to_owned(),
+            Some("5".to_owned()),
+        )
+        .send()
+        .await;
+
+    assert!(res.unwrap().success);
+}
+
+#[tokio::test]
+async fn simple_recipient_rejects_parcel_over_warehouse_limits() {
+    let np_client = NPClient::default().unwrap();
+    let warehouses = warehouses();
+
+    let res = sender_builder(&np_client, 40.0)
+        .simple_recipient_info(
+            "Антонич Богдан Ігор".to_owned(),
+            UaPhone::from_str("380660000000").unwrap(),
+            &warehouses[1],
+        )
+        .options_seat(vec![Seat::new(30.0, 40.0, 130.0, 20.0), Seat::new(30.0, 40.0, 50.0, 20.0)])
+        .send()
+        .await;
+
+    match res {
+        Err(CreateIDocumentError::Invalid(errors)) => assert_eq!(
+            errors,
+            vec![
+                IDocumentViolation::RecipientWarehouse(RecipientWarehouseError::TooHeavy(30)),
+                IDocumentViolation::RecipientWarehouse(RecipientWarehouseError::SeatTooLarge(0)),
+            ]
+        ),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn simple_recipient_without_seats_checks_weight_split() {
+    let np_client = NPClient::default().unwrap();
+    let warehouses = warehouses();
+
+    let res = sender_builder(&np_client, 2400.0)
+        .simple_recipient_info(
+            "Антонич Богдан Ігор".to_owned(),
+            UaPhone::from_str("380660000000").unwrap(),
+            &warehouses[0],
+        )
+        .send()
+        .await;
+
+    match res {
+        Err(CreateIDocumentError::Invalid(errors)) => assert_eq!(
+            errors,
+            vec![IDocumentViolation::RecipientWarehouse(RecipientWarehouseError::SeatTooHeavy(0))]
+        ),
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let res = sender_builder(&np_client, 40.0)
+        .simple_recipient_info(
+            "Антонич Богдан Ігор".to_owned(),
+            UaPhone::from_str("380660000000").unwrap(),
+            &warehouses[1],
+        )
+        .validate();
+
+    assert_eq!(res, Err(vec![IDocumentViolation::RecipientWarehouse(RecipientWarehouseError::TooHeavy(30))]));
+}
+
+#[tokio::test]
+async fn create_internet_document_with_additional_services() {
+    let preferred_date = kyiv_now().date() + Days::new(3);
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "InternetDocument",
+        "calledMethod": "save",
+        "methodProperties": {
+            "AdditionalInformation": "крихке",
+            "InfoRegClientBarcodes": "ORDER-1024",
+            "NumberOfFloorsLifting": "5",
+            "Elevator": 1,
+            "SaturdayDelivery": true,
+            "PreferredDeliveryDate": preferred_date.format("%d.%m.%Y").to_string(),
+            "AccompanyingDocuments": "накладна",
+            "RedBoxBarcode": "RB123456",
+            "OptionsSeat": [
+                { "packRef": "1499fa4a-d26e-11e1-95e4-0026b97ed48a" },
+                { "weight": "5" }
+            ]
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .additional_information("крихке".to_owned())
+        .client_barcode(ClientBarcode::from_str("ORDER-1024").unwrap())
+        .floors_lifting(5, true)
+        .saturday_delivery(true)
+        .preferred_delivery_date(preferred_date)
+        .accompanying_documents("накладна".to_owned())
+        .red_box_barcode("RB123456".to_owned())
+        .options_seat(vec![
+            Seat::new(30.0, 40.0, 50.0, 10.0).pack_ref(PackID::from_str("1499fa4a-d26e-11e1-95e4-0026b97ed48a").unwrap()),
+            Seat::new(20.0, 20.0, 20.0, 5.0),
+        ])
         .send()
         .await;
 
     assert!(res.is_ok());
     assert!(res.unwrap().success);
 }
+
+#[test]
+fn validate_collects_all_violations() {
+    let np_client = NPClient::default().unwrap();
+
+    let builder = np_client
+        .i_document()
+        .create_document()
+        .general_info(
+            CounterpartyRole::Recipient,
+            PaymentMethod::NonCash,
+            NaiveDate::from_ymd_opt(2023, 10, 11).unwrap(),
+            CargoType::Documents,
+            2.0,
+            ServiceType::WarehouseWarehouse,
+            1,
+            " ".to_owned(),
+            120,
+        )
+        .sender_info(
+            CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap(),
+            CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap(),
+            AddressID::from_str("1ec09d2d-e1c2-11e3-8c4a-0050568002cf").unwrap(),
+            ContactPersonID::from_str("57f35831-596f-11ee-a60f-48df37b921db").unwrap(),
+            UaPhone::from_str("380660000001").unwrap()
+        )
+        .simple_recipient_info(
+            "Антонич Богдан Ігор".to_owned(),
+            UaPhone::from_str("380660000000").unwrap(),
+            &warehouses()[0],
+        );
+
+    assert_eq!(
+        builder.validate(),
+        Err(vec![
+            IDocumentViolation::DateInPast,
+            IDocumentViolation::EmptyDescription,
+            IDocumentViolation::DocumentsTooHeavy,
+            IDocumentViolation::NonCashForPrivatePerson,
+        ])
+    );
+    assert!(complete_builder(&np_client).validate().is_ok());
+}
+
+#[test]
+fn validate_checks_option_combinations() {
+    let np_client = NPClient::default().unwrap();
+
+    let builder = complete_builder(&np_client)
+        .backward_delivery(BackwardDelivery::money(CounterpartyRole::Recipient, 4552.0))
+        .afterpayment_on_goods_cost(4552.0)
+        .time_interval(TimeIntervalCode::from_str("CityDeliveryTimeInterval2").unwrap());
+    assert_eq!(
+        builder.validate(),
+        Err(vec![IDocumentViolation::AfterpaymentWithMoneyBack, IDocumentViolation::TimeIntervalWithoutDoorDelivery])
+    );
+
+    let builder = complete_builder(&np_client)
+        .backward_delivery(BackwardDelivery::documents(CounterpartyRole::Sender, "накладна".to_owned()))
+        .afterpayment_on_goods_cost(4552.0);
+    assert!(builder.validate().is_ok());
+}
+
+#[test]
+fn validate_checks_pre_created_recipient_type() {
+    let np_client = NPClient::default().unwrap();
+
+    let builder = paid_by_recipient_non_cash(&np_client).recipient_type(CounterpartyType::PrivatePerson);
+    assert_eq!(builder.validate(), Err(vec![IDocumentViolation::NonCashForPrivatePerson]));
+
+    let builder = paid_by_recipient_non_cash(&np_client).recipient_type(CounterpartyType::Organization);
+    assert!(builder.validate().is_ok());
+}
+
+fn paid_by_recipient_non_cash(np_client: &NPClient) -> CreateIDocumentBuilder<'_, IDGeneral, SenderInfo, RecepientInfo> {
+    np_client
+        .i_document()
+        .create_document()
+        .general_info(
+            CounterpartyRole::Recipient,
+            PaymentMethod::NonCash,
+            kyiv_now().date() + Days::new(1),
+            CargoType::Parcel,
+            2.0,
+            ServiceType::WarehouseWarehouse,
+            1,
+            "посилка".to_owned(),
+            120,
+        )
+        .sender_info(
+            CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap(),
+            CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap(),
+            AddressID::from_str("1ec09d2d-e1c2-11e3-8c4a-0050568002cf").unwrap(),
+            ContactPersonID::from_str("57f35831-596f-11ee-a60f-48df37b921db").unwrap(),
+            UaPhone::from_str("380660000001").unwrap()
+        )
+        .recipient_info(
+            CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap(),
+            CounterpartyID::from_str("580c30f7-596f-11ee-a60f-48df37b921db").unwrap(),
+            AddressID::from_str("39633d8b-645f-11ee-a60f-48df37b921db").unwrap(),
+            ContactPersonID::from_str("c1ffd9b4-643e-11ee-a60f-48df37b921db").unwrap(),
+            UaPhone::from_str("380660000000").unwrap()
+        )
+}
+
+#[test]
+fn validate_checks_seats_against_totals() {
+    let np_client = NPClient::default().unwrap();
+
+    let builder = complete_builder(&np_client)
+        .options_seat(vec![Seat::new(30.0, 40.0, 50.0, 10.0)]);
+    assert_eq!(
+        builder.validate(),
+        Err(vec![IDocumentViolation::SeatsAmountMismatch, IDocumentViolation::SeatsWeightMismatch])
+    );
+
+    let builder = complete_builder(&np_client)
+        .options_seat(vec![Seat::new(30.0, 40.0, 50.0, 7.5), Seat::new(30.0, 40.0, 50.0, 7.5)]);
+    assert!(builder.validate().is_ok());
+}
+
+#[tokio::test]
+async fn create_many_resumes_from_checkpoint() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "save" })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let warehouses = warehouses();
+    let items = || {
+        [("order-1", 15.0), ("order-2", 0.0), ("order-3", 10.0)]
+            .into_iter()
+            .map(|(key, weight)| {
+                let builder = sender_builder(&np_client, weight).simple_recipient_info(
+                    "Антонич Богдан Ігор".to_owned(),
+                    UaPhone::from_str("380660000000").unwrap(),
+                    &warehouses[0],
+                );
+                (key.to_owned(), builder)
+            })
+            .collect::<Vec<_>>()
+    };
+    let checkpoint = std::env::temp_dir().join(format!("np_create_many_{}.json", std::process::id()));
+
+    let first = np_client.i_document().create_many(items()).checkpoint(&checkpoint).send().await.unwrap();
+    let second = np_client.i_document().create_many(items()).checkpoint(&checkpoint).send().await.unwrap();
+    std::fs::remove_file(&checkpoint).unwrap();
+
+    let keys = first.iter().map(|item| item.key().as_str()).collect::<Vec<_>>();
+    assert_eq!(keys, ["order-1", "order-2", "order-3"]);
+    assert!(first.iter().all(|item| !item.from_checkpoint()));
+    assert!(matches!(
+        first[1].result(),
+        Err(CreateIDocumentError::Invalid(ref violations)) if violations == &[IDocumentViolation::NonPositiveWeight]
+    ));
+
+    assert!(second[0].from_checkpoint() && second[2].from_checkpoint());
+    assert_eq!(
+        second[0].result().as_ref().unwrap().int_doc_number().as_ref(),
+        "20450788155600"
+    );
+    assert!(second[1].result().is_err());
+}
+
+#[tokio::test]
+async fn create_many_keeps_results_when_checkpoint_write_fails() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let items = ["order-1", "order-2"]
+        .into_iter()
+        .map(|key| (key.to_owned(), complete_builder(&np_client)))
+        .collect::<Vec<_>>();
+    let checkpoint = std::env::temp_dir()
+        .join(format!("np_missing_dir_{}", std::process::id()))
+        .join("checkpoint.json");
+
+    let results = np_client.i_document().create_many(items).checkpoint(&checkpoint).send().await.unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|item| item.result().is_ok() && item.checkpoint_error().is_some()));
+}
+
+#[tokio::test]
+async fn send_idempotent_finds_document_after_timeout() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let today = kyiv_now().date();
+    let from = (today - Days::new(1)).format("%d.%m.%Y").to_string();
+    let to = (today + Days::new(1)).format("%d.%m.%Y").to_string();
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({
+            "calledMethod": "save",
+            "methodProperties": { "InfoRegClientBarcodes": "ORDER-1024" }
+        })))
+        .respond_with(ResponseTemplate::new(200)
+            .set_body_raw(
+                include_str!("resources/create_internet_document_response.json"),
+                "application/json",
+            )
+            .set_delay(Duration::from_secs(6)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({
+            "modelName": "InternetDocument",
+            "calledMethod": "getDocumentList",
+            "methodProperties": {
+                "DateTimeFrom": from,
+                "DateTimeTo": to,
+                "GetFullList": "1"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/document_list_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .client_barcode(ClientBarcode::from_str("ORDER-1024").unwrap())
+        .send_idempotent(3)
+        .await
+        .unwrap();
+
+    assert!(matches!(res, SendOutcome::AlreadyExists(_)));
+    assert_eq!(res.int_doc_number().as_ref(), "20450788155600");
+}
+
+#[tokio::test]
+async fn retry_idempotent_looks_up_before_sending() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "save" })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "getDocumentList" })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/document_list_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .client_barcode(ClientBarcode::from_str("ORDER-1024").unwrap())
+        .retry_idempotent(3)
+        .await
+        .unwrap();
+
+    assert!(matches!(res, SendOutcome::AlreadyExists(_)));
+}
+
+#[tokio::test]
+async fn retry_idempotent_stops_on_rejected_lookup() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "save" })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_internet_document_response.json"),
+            "application/json",
+        ))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "getDocumentList" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": false,
+            "data": [],
+            "errors": ["API key expired"],
+            "warnings": [],
+            "info": [],
+            "messageCodes": [],
+            "errorCodes": [],
+            "warningCodes": [],
+            "infoCodes": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .client_barcode(ClientBarcode::from_str("ORDER-1024").unwrap())
+        .retry_idempotent(3)
+        .await;
+
+    match res {
+        Err(CreateIDocumentError::LookupRejected(errors)) => assert_eq!(errors, ["API key expired"]),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn send_idempotent_looks_up_after_server_error() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "save" })))
+        .respond_with(ResponseTemplate::new(504))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "getDocumentList" })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/document_list_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .client_barcode(ClientBarcode::from_str("ORDER-1024").unwrap())
+        .send_idempotent(3)
+        .await
+        .unwrap();
+
+    assert!(matches!(res, SendOutcome::AlreadyExists(_)));
+}
+
+#[tokio::test]
+async fn send_idempotent_gives_up_on_client_error() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "save" })))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({ "calledMethod": "getDocumentList" })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let res = complete_builder(&np_client)
+        .client_barcode(ClientBarcode::from_str("ORDER-1024").unwrap())
+        .send_idempotent(3)
+        .await;
+
+    assert!(matches!(res, Err(CreateIDocumentError::Request(err)) if err.status().is_some()));
+}
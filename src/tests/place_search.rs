@@ -0,0 +1,134 @@
+use crate::np_client::address::cities::City;
+use crate::np_client::address::place_search::{MatchKind, PlaceIndex};
+use crate::np_client::address::settlements::{SettlementEntry, Settlements};
+use crate::np_client::res_template::ResponseTemplate as NPResponseTemplate;
+use crate::np_client::NPClient;
+
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn cities() -> PlaceIndex<City> {
+    let res: NPResponseTemplate<City> = serde_json::from_str(
+        include_str!("resources/cities_directory_response.json")
+    ).unwrap();
+    PlaceIndex::new(res.data)
+}
+
+fn first<'a>(index: &'a PlaceIndex<City>, query: &str) -> Option<(&'a str, MatchKind)> {
+    index.search(query, 1).first().map(|(city, kind)| (city.description(), *kind))
+}
+
+#[test]
+fn search_across_spellings() {
+    let index = cities();
+
+    for query in ["Київ", "киев", "Kyiv", "Kiev", "KIEV"] {
+        assert_eq!(first(&index, query), Some(("Київ", MatchKind::Exact)), "{query}");
+    }
+    assert_eq!(first(&index, "Kharkov"), Some(("Харків", MatchKind::Exact)));
+    assert_eq!(first(&index, "Odessa"), Some(("Одеса", MatchKind::Exact)));
+}
+
+#[test]
+fn search_prefix_and_typos() {
+    let index = cities();
+
+    let found = index.search("ки", 5).into_iter().map(|(city, _)| city.description()).collect::<Vec<_>>();
+    assert_eq!(found, ["Київ", "Київець"]);
+
+    assert_eq!(first(&index, "церк"), Some(("Біла Церква", MatchKind::WordPrefix)));
+    assert_eq!(first(&index, "Lviw"), Some(("Львів", MatchKind::Exact)));
+    assert_eq!(first(&index, "Харкві"), Some(("Харків", MatchKind::Fuzzy(1))));
+    assert_eq!(first(&index, "Bila Tserkvq"), Some(("Біла Церква", MatchKind::Fuzzy(1))));
+    assert!(index.search("Житомир", 5).is_empty());
+}
+
+#[test]
+fn search_settlements() {
+    let res: NPResponseTemplate<Settlements> = serde_json::from_str(
+        include_str!("resources/settlement_search_response.json")
+    ).unwrap();
+    let settlements = res.data.into_iter().next().unwrap();
+    let index = PlaceIndex::new(settlements.settlements().to_vec());
+
+    let found = index.search("Nemyriv", 10);
+    assert_eq!(found.len(), 6);
+    assert_eq!(found[0].1, MatchKind::Exact);
+    assert_eq!(found[0].0.main_description(), "Немирів");
+}
+
+#[tokio::test]
+async fn sync_cities_and_reload() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({
+            "calledMethod": "getCities",
+            "methodProperties": { "Page": 1, "Limit": 500 }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/cities_directory_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let index = PlaceIndex::sync_cities(&np_client).await.unwrap();
+    let file = std::env::temp_dir().join(format!("np_cities_{}.json", std::process::id()));
+    index.save(&file).unwrap();
+    let index = PlaceIndex::<City>::load(&file).unwrap();
+    std::fs::remove_file(&file).unwrap();
+
+    assert_eq!(index.places().len(), 6);
+    assert_eq!(first(&index, "lviv"), Some(("Львів", MatchKind::Exact)));
+}
+
+#[tokio::test]
+async fn sync_settlements_and_reload() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let first_page: Value = serde_json::from_str(include_str!("resources/settlements_directory_response.json")).unwrap();
+    let mut second_page = first_page.clone();
+    let mut settlement = first_page["data"][1].clone();
+    settlement["Ref"] = json!("e71b4e71-4b33-11e4-ab6d-005056801329");
+    settlement["Description"] = json!("Одеса");
+    settlement["DescriptionRu"] = json!("Одесса");
+    settlement["DescriptionTranslit"] = json!("Odesa");
+    second_page["data"] = json!([settlement]);
+
+    for (page, body) in [(1, first_page), (2, second_page)] {
+        Mock::given(path("/"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "modelName": "AddressGeneral",
+                "calledMethod": "getSettlements",
+                "methodProperties": { "Page": page, "Limit": 500 }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let index = PlaceIndex::sync_settlements(&np_client).await.unwrap();
+    let file = std::env::temp_dir().join(format!("np_settlements_{}.json", std::process::id()));
+    index.save(&file).unwrap();
+    let index = PlaceIndex::<SettlementEntry>::load(&file).unwrap();
+    std::fs::remove_file(&file).unwrap();
+
+    assert_eq!(index.places().len(), 3);
+    let found = index.search("Киев", 1);
+    assert_eq!(found[0].0.description(), "Київ");
+    assert!(found[0].0.region().is_none());
+    assert_eq!(index.search("Одесса", 1)[0].1, MatchKind::Exact);
+    assert!(!index.search("Абазовка", 1)[0].0.has_warehouses());
+}
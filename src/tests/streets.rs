@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, helper_structs::{CityID, SettlementID}};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn search_settlement_streets_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "searchSettlementStreets",
+        "methodProperties": {
+            "SettlementRef": "e718a680-4b33-11e4-ab6d-005056801329",
+            "StreetName": "хрещ",
+            "Page": 1,
+            "Limit": 10
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/settlement_streets_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .address()
+        .search_settlement_streets(
+            SettlementID::from_str("e718a680-4b33-11e4-ab6d-005056801329").unwrap(),
+            "хрещ".to_owned(),
+        )
+        .page(1)
+        .limit(10)
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data[0].total_count(), 2);
+    assert_eq!(res.data[0].streets()[0].description(), "Хрещатик");
+}
+
+#[tokio::test]
+async fn get_streets_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "getStreet",
+        "methodProperties": {
+            "CityRef": "8d5a980d-391c-11dd-90d9-001a92567626",
+            "FindByString": "Хрещатик",
+            "Page": 1
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/streets_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .address()
+        .get_streets(
+            CityID::from_str("8d5a980d-391c-11dd-90d9-001a92567626").unwrap(),
+            "Хрещатик".to_owned(),
+        )
+        .page(1)
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    assert_eq!(res.data.len(), 1);
+}
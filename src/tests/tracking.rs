@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
-use crate::np_client::{NPClient, en::ENumber};
+use crate::np_client::{NPClient, en::{ClientBarcode, ClientBarcodeError, ENumber, ENumberKind, ENumberParsingError}, phone::UaPhone};
 use crate::np_client::res_template::ResponseTemplate as NPResponseTemplate;
 
 use serde_json::json;
@@ -22,7 +23,7 @@ async fn tracking_request_ok() {
             "Documents": [
                 {
                     "DocumentNumber": "20450777813966",
-                    "Phone": "380123456787"
+                    "Phone": "380673456787"
                 }
             ]
         }
@@ -41,7 +42,10 @@ async fn tracking_request_ok() {
 
     let res = np_client
         .tracking()
-        .track_parcel(ENumber::from_str("20450777813966").unwrap(), "380123456787".to_owned())
+        .track_parcel(
+            ENumber::from_str("20450777813966").unwrap(),
+            Some(UaPhone::from_str("0673456787").unwrap()),
+        )
         .send()
         .await;
 
@@ -81,7 +85,7 @@ async fn tracking_request_without_phone() {
 
     let res = np_client
         .tracking()
-        .track_parcel(ENumber::from_str("20450777813966").unwrap(), "".to_owned())
+        .track_parcel(ENumber::from_str("20450777813966").unwrap(), None)
         .send()
         .await;
 
@@ -90,6 +94,34 @@ async fn tracking_request_without_phone() {
     assert!(res.success);
     assert!(!res.warnings.is_empty());
 }
+
+#[tokio::test]
+async fn tracking_response_with_free_form_client_barcode() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/tracking_response_client_barcode.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .tracking()
+        .track_parcel(ENumber::from_str("20450777813966").unwrap(), None)
+        .send()
+        .await
+        .unwrap();
+
+    // Barcodes set in the web cabinet aren't restricted to what this crate sends.
+    assert_eq!(res.data[0].client_barcode(), Some("замовлення 5.1"));
+}
+
 #[tokio::test]
 async fn tracking_request_invalid_en() {
     let mock_server = MockServer::start().await;
@@ -140,7 +172,7 @@ async fn tracking_request_invalid_en() {
 
     let res = np_client
         .tracking()
-        .track_parcel(ENumber::from_str("20450777813966").unwrap(), "".to_owned())
+        .track_parcel(ENumber::from_str("20450777813966").unwrap(), None)
         .send()
         .await;
 
@@ -150,3 +182,32 @@ async fn tracking_request_invalid_en() {
     assert!(!res.warnings.is_empty());
     assert!(!res.errors.is_empty());
 }
+
+#[test]
+fn en_validation() {
+    let en = ENumber::from_str("20450777813966").unwrap();
+    assert_eq!(en.kind(), ENumberKind::Domestic);
+    assert_eq!(en.to_string(), "20450777813966");
+
+    let en = ENumber::from_str("20600001234567890123").unwrap();
+    assert_eq!(en.kind(), ENumberKind::International);
+
+    assert_eq!(ENumber::from_str("1234567890abcd"), Err(ENumberParsingError::InvalidSymbols));
+    assert_eq!(ENumber::from_str("2045077781396"), Err(ENumberParsingError::InvalidLength));
+    assert_eq!(ENumber::from_str(""), Err(ENumberParsingError::Empty));
+
+    // Unknown formats in responses are kept rather than failing the response.
+    let en: ENumber = serde_json::from_value(json!("NP-0001234")).unwrap();
+    assert_eq!(en.kind(), ENumberKind::Other);
+    assert_eq!(en.as_ref(), "NP-0001234");
+
+    let mut cache = HashMap::new();
+    cache.insert(ENumber::from_str("20450777813966").unwrap(), 1);
+    assert_eq!(cache.get(&ENumber::from_str("20450777813966").unwrap()), Some(&1));
+
+    assert!(ClientBarcode::from_str("ORDER-1024/A").is_ok());
+    assert!(ClientBarcode::from_str("замовлення №5").is_ok());
+    assert_eq!(ClientBarcode::from_str(""), Err(ClientBarcodeError::Empty));
+    assert_eq!(ClientBarcode::from_str(&"x".repeat(37)), Err(ClientBarcodeError::TooLong));
+    assert_eq!(ClientBarcode::from_str(" ORDER-1"), Err(ClientBarcodeError::InvalidSymbols));
+}
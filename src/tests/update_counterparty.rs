@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use crate::np_client::{NPClient, phone::UaPhone, helper_structs::{CounterpartyID, CounterpartyRole, CounterpartyType}};
+
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn update_counterparty_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Counterparty",
+        "calledMethod": "update",
+        "methodProperties": {
+            "Ref": "580c30f7-596f-11ee-a60f-48df37b921db",
+            "FirstName": "Іван",
+            "MiddleName": "Іванович",
+            "LastName": "Іван",
+            "Phone": "380997979780",
+            "CounterpartyType": "PrivatePerson",
+            "CounterpartyProperty": "Recipient"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_counterparty_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .update_counterparty(
+            CounterpartyID::from_str("580c30f7-596f-11ee-a60f-48df37b921db").unwrap(),
+            CounterpartyRole::Recipient,
+            CounterpartyType::PrivatePerson,
+        )
+        .name("Іван".to_owned(), "Іванович".to_owned(), "Іван".to_owned())
+        .phone(UaPhone::from_str("380997979780").unwrap())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    assert!(res.unwrap().success);
+}
+
+#[tokio::test]
+async fn update_organization_without_name() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({
+            "calledMethod": "update",
+            "methodProperties": {
+                "Ref": "580c30f7-596f-11ee-a60f-48df37b921db",
+                "CounterpartyType": "Organization",
+                "CounterpartyProperty": "Recipient",
+                "Email": "office@example.com"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/create_counterparty_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .update_counterparty(
+            CounterpartyID::from_str("580c30f7-596f-11ee-a60f-48df37b921db").unwrap(),
+            CounterpartyRole::Recipient,
+            CounterpartyType::Organization,
+        )
+        .phone(UaPhone::from_str("380997979780").unwrap())
+        .email("office@example.com".to_owned())
+        .send()
+        .await;
+
+    assert!(res.unwrap().success);
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = requests[0].body_json().unwrap();
+    assert!(body["methodProperties"].get("FirstName").is_none());
+}
+
+#[tokio::test]
+async fn counterparty_options_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Counterparty",
+        "calledMethod": "getCounterpartyOptions",
+        "methodProperties": {
+            "Ref": "57f2c3c2-596f-11ee-a60f-48df37b921db"
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/counterparty_options_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .counterparty()
+        .get_counterparty_options(CounterpartyID::from_str("57f2c3c2-596f-11ee-a60f-48df37b921db").unwrap())
+        .send()
+        .await;
+
+    assert!(res.is_ok());
+    let res = res.unwrap();
+    assert!(res.success);
+    let options = &res.data[0];
+    assert!(!options.can_non_cash_payment());
+    assert!(options.can_afterpayment_on_goods_cost());
+    assert!(options.has_loyalty_card());
+}
@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::np_client::address::warehouse_directory::WarehouseDirectory;
+use crate::np_client::address::warehouses::WarehouseKind;
+use crate::np_client::helper_structs::{CityID, SettlementID, WarehouseID};
+use crate::np_client::NPClient;
+
+use chrono::Duration;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn warehouse_directory_sync_and_reload() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "getWarehouses",
+        "methodProperties": {
+            "Page": 1,
+            "Limit": 500,
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/warehouses_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mount_empty_page(&mock_server, 2).await;
+
+    let directory = WarehouseDirectory::sync(&np_client).await.unwrap();
+    assert_eq!(directory.len(), 2);
+    assert!(!directory.is_stale(Duration::hours(1)));
+
+    let file = std::env::temp_dir().join(format!("np_warehouses_{}.json", std::process::id()));
+    directory.save(&file).unwrap();
+    let directory = WarehouseDirectory::load(&file).unwrap();
+    std::fs::remove_file(&file).unwrap();
+
+    let kyiv = CityID::from_str("db5c88d4-391c-11dd-90d9-001a92567626").unwrap();
+    assert_eq!(directory.len(), 2);
+    assert_eq!(directory.by_city(kyiv).len(), 2);
+    assert_eq!(
+        directory.by_number(kyiv, 2).map(|w| w.r#ref()),
+        Some(WarehouseID::from_str("511fcffb-e1c2-11e3-8c4a-0050568002cf").unwrap())
+    );
+    assert!(directory.by_number(kyiv, 3).is_none());
+    assert_eq!(
+        directory.by_settlement(SettlementID::from_str("e714e1ce-4b33-11e4-ab6d-005056801329").unwrap()).len(),
+        2
+    );
+    assert_eq!(
+        directory.by_type(WarehouseKind::CargoBranch).len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn warehouse_directory_sync_reads_every_page() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    // Short pages: the API caps the page size below the requested limit.
+    let fixture: Value = serde_json::from_str(include_str!("resources/warehouses_response.json")).unwrap();
+    for page in 1..=2 {
+        let mut warehouse = fixture["data"][0].clone();
+        warehouse["Ref"] = json!(format!("1ec09d88-e1c2-11e3-8c4a-0050568002c{page}"));
+        let mut body = fixture.clone();
+        body["data"] = json!([warehouse]);
+        body["info"] = json!({});
+
+        Mock::given(path("/"))
+            .and(method("POST"))
+            .and(body_partial_json(json!({
+                "calledMethod": "getWarehouses",
+                "methodProperties": { "Page": page, "Limit": 500 }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    mount_empty_page(&mock_server, 3).await;
+
+    let directory = WarehouseDirectory::sync(&np_client).await.unwrap();
+    assert_eq!(directory.len(), 2);
+    assert!(directory.get(WarehouseID::from_str("1ec09d88-e1c2-11e3-8c4a-0050568002c2").unwrap()).is_some());
+}
+
+async fn mount_empty_page(mock_server: &MockServer, page: u16) {
+    let mut body: Value = serde_json::from_str(include_str!("resources/warehouses_response.json")).unwrap();
+    body["data"] = json!([]);
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(json!({
+            "calledMethod": "getWarehouses",
+            "methodProperties": { "Page": page }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
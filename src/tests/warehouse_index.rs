@@ -0,0 +1,94 @@
+use crate::np_client::address::warehouse_index::{WarehouseFilter, WarehouseIndex};
+use crate::np_client::address::warehouses::{ParcelSpec, Warehouse, WarehouseHours};
+use crate::np_client::helper_structs::CargoType;
+use crate::np_client::res_template::ResponseTemplate;
+
+use chrono::NaiveDate;
+
+fn warehouses() -> Vec<Warehouse> {
+    let res: ResponseTemplate<Warehouse> = serde_json::from_str(
+        include_str!("resources/warehouses_nearby_response.json")
+    ).unwrap();
+    res.data
+}
+
+// Near the Boryspil city park.
+const LAT: f64 = 50.3535;
+const LON: f64 = 30.9505;
+
+#[test]
+fn nearest_warehouses_sorted_by_distance() {
+    let warehouses = warehouses();
+    let index = WarehouseIndex::new(&warehouses);
+
+    let nearest = index.nearest(LAT, LON, 3, &WarehouseFilter::new());
+    let numbers = nearest.iter().map(|(w, _)| w.number()).collect::<Vec<_>>();
+    assert_eq!(numbers, [3, 2, 1]);
+    assert!(nearest[0].1 < 0.1);
+
+    let all = index.nearest(LAT, LON, 10, &WarehouseFilter::new());
+    assert_eq!(all.len(), 4);
+    assert!(all[3].1 > 400.0);
+}
+
+#[test]
+fn nearest_warehouses_filtered() {
+    let warehouses = warehouses();
+    let index = WarehouseIndex::new(&warehouses);
+
+    let branches = index.nearest(LAT, LON, 5, &WarehouseFilter::new().postomat(false).pos_terminal());
+    assert!(branches.iter().all(|(w, _)| !w.is_postomat()));
+    assert_eq!(branches.len(), 3);
+
+    let heavy = index.nearest(LAT, LON, 5, &WarehouseFilter::new().place_weight(50));
+    assert!(heavy.iter().all(|(w, _)| w.number() != 3));
+
+    let pallets = index.nearest(LAT, LON, 5, &WarehouseFilter::new().accepts(CargoType::Pallet));
+    assert!(pallets.iter().all(|(w, _)| w.number() == 1));
+
+    // Sunday 21:00, only the postomat works.
+    let late = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap().and_hms_opt(21, 0, 0).unwrap();
+    let open = index.nearest(LAT, LON, 5, &WarehouseFilter::new().open_at(late));
+    assert_eq!(open.len(), 1);
+    assert!(open[0].0.is_postomat());
+}
+
+#[test]
+fn nearest_warehouses_by_reception_hours() {
+    let warehouses = warehouses();
+    let index = WarehouseIndex::new(&warehouses);
+
+    let working = index.nearest(LAT, LON, 5, &WarehouseFilter::new().works_on_sundays());
+    let reception = index.nearest(LAT, LON, 5, &WarehouseFilter::new().hours(WarehouseHours::Reception).works_on_sundays());
+    assert_eq!(working.len(), 4);
+    assert_eq!(reception.len(), 3);
+
+    // Sunday 18:30, still working but no longer taking parcels.
+    let evening = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap().and_hms_opt(18, 30, 0).unwrap();
+    let open = index.nearest(LAT, LON, 5, &WarehouseFilter::new().hours(WarehouseHours::Reception).open_at(evening));
+    assert!(open.iter().all(|(w, _)| w.is_postomat()));
+}
+
+#[test]
+fn warehouses_within_radius() {
+    let warehouses = warehouses();
+    let index = WarehouseIndex::new(&warehouses);
+
+    let close = index.within_radius(LAT, LON, 1.0, &WarehouseFilter::new());
+    assert_eq!(close.iter().map(|(w, _)| w.number()).collect::<Vec<_>>(), [3, 2]);
+
+    let wide = index.within_radius(LAT, LON, 10.0, &WarehouseFilter::new());
+    assert_eq!(wide.len(), 3);
+}
+
+#[test]
+fn nearest_warehouses_accepting_parcel() {
+    let warehouses = warehouses();
+    let index = WarehouseIndex::new(&warehouses);
+
+    let parcel = ParcelSpec::new().seat(30.0, 40.0, 20.0, 25.0);
+    let found = index.nearest(LAT, LON, 5, &WarehouseFilter::new().can_accept(parcel));
+
+    assert!(found.iter().all(|(w, _)| !w.is_postomat()));
+    assert_eq!(found.len(), 3);
+}
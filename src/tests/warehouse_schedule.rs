@@ -0,0 +1,103 @@
+use crate::np_client::address::schedule::Schedule;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use serde_json::json;
+
+fn schedule() -> Schedule {
+    serde_json::from_value(json!({
+        "Monday": "08:00-13:00,14:00-20:00",
+        "Tuesday": "08:00-20:00",
+        "Wednesday": "08:00-20:00",
+        "Thursday": "08:00-20:00",
+        "Friday": "08:00-20:00",
+        "Saturday": "09:00-15:00",
+        "Sunday": "-"
+    })).unwrap()
+}
+
+// 2023-10-16 is a Monday.
+fn at(day: u32, hour: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2023, 10, day).unwrap().and_hms_opt(hour, min, 0).unwrap()
+}
+
+#[test]
+fn schedule_open_at() {
+    let schedule = schedule();
+
+    assert!(schedule.is_open_at(at(16, 8, 0)));
+    assert!(!schedule.is_open_at(at(16, 13, 30)));
+    assert!(!schedule.is_open_at(at(16, 20, 0)));
+    assert!(!schedule.is_open_at(at(22, 12, 0)));
+    assert_eq!(schedule.intervals(Weekday::Mon).len(), 2);
+    assert_eq!(schedule.open_until(at(16, 15, 0)), NaiveTime::from_hms_opt(20, 0, 0));
+    assert_eq!(schedule.open_until(at(16, 21, 0)), None);
+    assert!(!schedule.works_on_sundays());
+}
+
+#[test]
+fn schedule_next_opening() {
+    let schedule = schedule();
+
+    assert_eq!(schedule.next_opening(at(16, 10, 0)), Some(at(16, 10, 0)));
+    assert_eq!(schedule.next_opening(at(16, 13, 10)), Some(at(16, 14, 0)));
+    assert_eq!(schedule.next_opening(at(21, 16, 0)), Some(at(23, 8, 0)));
+
+    let closed: Schedule = serde_json::from_value(json!({
+        "Monday": "-", "Tuesday": "-", "Wednesday": "-", "Thursday": "-",
+        "Friday": "-", "Saturday": "-", "Sunday": "-"
+    })).unwrap();
+    assert_eq!(closed.next_opening(at(16, 10, 0)), None);
+}
+
+#[test]
+fn schedule_round_trip() {
+    let value = serde_json::to_value(schedule()).unwrap();
+
+    assert_eq!(value["Monday"], "08:00-13:00,14:00-20:00");
+    assert_eq!(value["Sunday"], "-");
+    assert_eq!(serde_json::from_value::<Schedule>(value).unwrap(), schedule());
+}
+
+#[test]
+fn schedule_overnight_range() {
+    let schedule: Schedule = serde_json::from_value(json!({
+        "Monday": "08:00-20:00", "Tuesday": "08:00-20:00", "Wednesday": "08:00-20:00",
+        "Thursday": "08:00-20:00", "Friday": "20:00-02:00", "Saturday": "-", "Sunday": "-"
+    })).unwrap();
+
+    // 2023-10-20 is a Friday.
+    assert!(schedule.is_open_at(at(20, 23, 0)));
+    assert!(schedule.is_open_at(at(21, 1, 30)));
+    assert!(!schedule.is_open_at(at(21, 2, 0)));
+    assert!(!schedule.is_open_at(at(20, 1, 0)));
+    assert_eq!(schedule.open_until(at(21, 1, 0)), NaiveTime::from_hms_opt(2, 0, 0));
+    assert_eq!(serde_json::to_value(&schedule).unwrap()["Friday"], "20:00-02:00");
+}
+
+#[test]
+fn schedule_keeps_unparsed_days() {
+    let schedule: Schedule = serde_json::from_value(json!({
+        "Monday": "08:00-20:00", "Tuesday": "08:00-20:00", "Wednesday": "08:00-20:00",
+        "Thursday": "08:00-20:00", "Friday": "08:00-20:00", "Saturday": "за домовленістю",
+        "Sunday": "-"
+    })).unwrap();
+
+    assert_eq!(schedule.unparsed(Weekday::Sat), Some("за домовленістю"));
+    assert_eq!(schedule.unparsed(Weekday::Sun), None);
+    assert!(!schedule.works_on(Weekday::Sat));
+    assert_eq!(serde_json::to_value(&schedule).unwrap()["Saturday"], "за домовленістю");
+}
+
+#[test]
+fn schedule_closing_at_midnight() {
+    let schedule: Schedule = serde_json::from_value(json!({
+        "Monday": "08:00-24:00", "Tuesday": "08:00-20:00", "Wednesday": "08:00-20:00",
+        "Thursday": "08:00-20:00", "Friday": "08:00-20:00", "Saturday": "-", "Sunday": "-"
+    })).unwrap();
+
+    assert!(schedule.intervals(Weekday::Mon)[0].closes_at_midnight());
+    assert!(!schedule.intervals(Weekday::Mon)[0].is_overnight());
+    assert!(schedule.is_open_at(at(16, 23, 59)));
+    assert!(!schedule.is_open_at(at(17, 0, 30)));
+    assert_eq!(serde_json::to_value(&schedule).unwrap()["Monday"], "08:00-24:00");
+}
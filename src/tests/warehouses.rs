@@ -1,3 +1,5 @@
+use crate::np_client::address::warehouses::{ParcelRejection, ParcelSpec, Warehouse, WarehouseKind, WarehouseStatus};
+use crate::np_client::res_template::ResponseTemplate as NPResponseTemplate;
 use crate::np_client::NPClient;
 
 use serde_json::json;
@@ -45,3 +47,102 @@ async fn warehouses_request_ok() {
     assert!(res.is_ok());
     assert!(res.unwrap().success);
 }
+
+#[tokio::test]
+async fn warehouses_request_by_kind() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "getWarehouses",
+        "methodProperties": {
+            "TypeOfWarehouseRef": "9a68df70-0267-42a8-bb5c-37f427e36ee4",
+        }
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/warehouses_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client
+        .address()
+        .get_warehouses()
+        .kind(WarehouseKind::CargoBranch)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(res.data[0].kind(), WarehouseKind::CargoBranch);
+    assert_eq!(res.data[0].status(), &WarehouseStatus::Working);
+    assert_eq!(res.data[1].kind(), WarehouseKind::Branch);
+}
+
+#[tokio::test]
+async fn warehouse_types_request_ok() {
+    let mock_server = MockServer::start().await;
+    let mut np_client = NPClient::default().unwrap();
+    np_client.base_url(&mock_server.uri());
+
+    let expected_body = json!({
+        "modelName": "Address",
+        "calledMethod": "getWarehouseTypes",
+    });
+
+    Mock::given(path("/"))
+        .and(method("POST"))
+        .and(body_partial_json(&expected_body))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("resources/warehouse_types_response.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = np_client.address().get_warehouse_types().send().await.unwrap();
+    let kinds = res.data.iter().map(|t| t.kind()).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [
+        WarehouseKind::ParcelShop,
+        WarehouseKind::Branch,
+        WarehouseKind::PostomatPrivat,
+        WarehouseKind::CargoBranch,
+        WarehouseKind::Postomat,
+    ]);
+}
+
+#[test]
+fn warehouse_parcel_limits() {
+    let res: NPResponseTemplate<Warehouse> = serde_json::from_str(
+        include_str!("resources/warehouses_response.json")
+    ).unwrap();
+    let branch = &res.data[1];
+
+    let rotated = ParcelSpec::new().seat(130.0, 20.0, 20.0, 5.0);
+    assert_eq!(
+        branch.can_accept(&rotated),
+        Err(vec![ParcelRejection::SeatTooLarge { seat: 0, side: 130.0, limit: 120 }])
+    );
+    assert!(branch.can_accept(&ParcelSpec::new().seat(20.0, 110.0, 20.0, 5.0)).is_ok());
+
+    let heavy = ParcelSpec::new()
+        .seat(20.0, 20.0, 20.0, 20.0)
+        .seat(20.0, 20.0, 20.0, 15.0)
+        .declared_cost(5000.0);
+    assert_eq!(branch.can_send(&heavy), Err(vec![ParcelRejection::TooHeavy { limit: 30 }]));
+
+    let cargo = &res.data[0];
+    assert_eq!(
+        cargo.can_send(&ParcelSpec::new().seat_by_weight(1200.0)),
+        Err(vec![ParcelRejection::SeatTooHeavy { seat: 0, limit: 1100 }])
+    );
+}